@@ -1,5 +1,6 @@
 use gruesome::disasm_txd::TxdDisassembler;
 use gruesome::instruction::{Instruction, InstructionForm};
+use gruesome::trap::Trap;
 use gruesome::vm::Game;
 use log::info;
 use std::collections::{HashMap, HashSet};
@@ -91,10 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for &addr in &extras {
         if let Some(pattern) = patterns.get(&addr) {
             let category = categorize_pattern(pattern);
-            categorized
-                .entry(category)
-                .or_default()
-                .push(addr);
+            categorized.entry(category).or_default().push(addr);
         }
     }
 
@@ -135,7 +133,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             // Try to decode first instruction
             match Instruction::decode(&game.memory, addr as usize, game.header.version) {
                 Ok(_) => other_missing += 1,
-                Err(e) if e.contains("Invalid Long form opcode 0x00") => invalid_opcodes += 1,
+                Err(e) if matches!(Trap::classify(&e), Trap::BadOpcode { opcode: 0x00, .. }) => {
+                    invalid_opcodes += 1
+                }
                 Err(_) => other_missing += 1,
             }
         }
@@ -1,4 +1,5 @@
 use gruesome::instruction::{Instruction, InstructionForm};
+use gruesome::trap::Trap;
 use gruesome::vm::Game;
 use std::fs;
 
@@ -109,7 +110,7 @@ fn categorize_routine(memory: &[u8], addr: u32, version: u8) -> Category {
             }
             Err(e) => {
                 // Hit invalid instruction
-                if e.contains("Invalid Long form opcode 0x00") {
+                if matches!(Trap::classify(&e), Trap::BadOpcode { opcode: 0x00, .. }) {
                     return Category::Other("Hits invalid opcode 0x00".to_string());
                 }
                 break;
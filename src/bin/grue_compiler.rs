@@ -6,7 +6,9 @@ use std::fs;
 use std::path::Path;
 use std::process;
 
-use gruesome::grue_compiler::{GrueCompiler, ZMachineVersion};
+use gruesome::grue_compiler::lexer::Lexer;
+use gruesome::grue_compiler::parser::Parser;
+use gruesome::grue_compiler::{dump_ast, GrueCompiler, ZMachineVersion};
 
 fn main() {
     // Initialize logging
@@ -26,6 +28,9 @@ fn main() {
     let mut print_ir = false;
     let mut dump_mapping = false;
     let mut debug_objects = false;
+    let mut debug_info = false;
+    let mut dump_tokens = false;
+    let mut dump_ast_flag = false;
 
     let mut i = 1;
     while i < args.len() {
@@ -110,6 +115,18 @@ fn main() {
                 debug_objects = true;
                 i += 1;
             }
+            "--debug-info" => {
+                debug_info = true;
+                i += 1;
+            }
+            "--dump-tokens" => {
+                dump_tokens = true;
+                i += 1;
+            }
+            "--dump-ast" => {
+                dump_ast_flag = true;
+                i += 1;
+            }
             "-h" | "--help" => {
                 print_usage(&args[0]);
                 process::exit(0);
@@ -170,6 +187,46 @@ fn main() {
         }
     };
 
+    if dump_tokens {
+        // Tokenize only, print the token stream, and exit before parsing/codegen
+        let mut lexer = Lexer::new(&source);
+        match lexer.tokenize() {
+            Ok(tokens) => {
+                println!("{}", gruesome::grue_compiler::lexer::format_tokens(&tokens));
+                process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("Lexical error: {}", err.render_with_source(&source));
+                process::exit(1);
+            }
+        }
+    }
+
+    if dump_ast_flag {
+        // Parse only, print the AST, and exit before codegen
+        let mut lexer = Lexer::new(&source);
+        let tokens = match lexer.tokenize() {
+            Ok(tokens) => tokens,
+            Err(err) => {
+                eprintln!("Lexical error: {}", err.render_with_source(&source));
+                process::exit(1);
+            }
+        };
+        let mut parser = Parser::new(tokens, &source);
+        match parser.parse() {
+            Ok(program) => {
+                println!("{}", dump_ast(&program));
+                process::exit(0);
+            }
+            Err(errors) => {
+                for err in errors {
+                    eprintln!("Parse error: {}", err.render_with_source(&source));
+                }
+                process::exit(1);
+            }
+        }
+    }
+
     // Compile
     let compiler = GrueCompiler::new();
 
@@ -181,14 +238,16 @@ fn main() {
                 gruesome::grue_compiler::print_ir(&ir_program);
                 process::exit(0);
             }
-            Err(err) => {
-                eprintln!("Compilation error: {}", err);
+            Err(errors) => {
+                for err in errors {
+                    eprintln!("Compilation error: {}", err.render_with_source(&source));
+                }
                 process::exit(1);
             }
         }
     }
 
-    match compiler.compile(&source, version) {
+    match compiler.compile_with_debug_info(&source, version, debug_info) {
         Ok((story_data, code_generator)) => {
             let data_size = story_data.len();
 
@@ -198,6 +257,17 @@ fn main() {
                 process::exit(1);
             }
 
+            if debug_info {
+                let debug_info_file = format!("{}.dbg", output_file);
+                if let Err(err) = code_generator.write_debug_info(&debug_info_file) {
+                    eprintln!("Error writing '{}': {}", debug_info_file, err);
+                    process::exit(1);
+                }
+                if verbose {
+                    println!("Wrote debug-info sidecar to {}", debug_info_file);
+                }
+            }
+
             if dump_mapping {
                 code_generator.dump_pc_mapping();
             }
@@ -215,8 +285,10 @@ fn main() {
                 );
             }
         }
-        Err(err) => {
-            eprintln!("Compilation error: {}", err);
+        Err(errors) => {
+            for err in errors {
+                eprintln!("Compilation error: {}", err.render_with_source(&source));
+            }
             process::exit(1);
         }
     }
@@ -230,8 +302,11 @@ fn print_usage(program_name: &str) {
     println!("  --version <v3|v4|v5>   Z-Machine version (default: v3)");
     println!("  -v, --verbose          Verbose output");
     println!("  --print-ir             Print intermediate representation and exit");
+    println!("  --dump-tokens          Print the lexer's token stream and exit");
+    println!("  --dump-ast             Print the parsed AST and exit");
     println!("  --dump-mapping         Dump PC→IR mapping after compilation");
     println!("  --debug-objects        Dump object table after compilation");
+    println!("  --debug-info           Emit a <output>.dbg address-to-symbol sidecar");
     println!("  -h, --help             Show this help message");
     println!();
     println!("Z-Machine Version Support:");
@@ -0,0 +1,438 @@
+// run-conformance-tests - JSON-driven single-instruction conformance test harness
+//
+// Loads gzipped JSON test suites (one JSON array of cases per file), in the
+// style of the per-instruction CPU conformance corpora used by other
+// emulator projects. Each case has a `name`, an `initial` VM state, and a
+// `final` VM state. The harness builds a VM, patches in `initial`,
+// single-steps exactly one instruction through `Interpreter`, then diffs
+// every field of the resulting state against `final`.
+//
+// This is a stronger check than `validate_extra_routines`'s "did it decode /
+// does it have a terminator" heuristics: it verifies that `Instruction::decode`
+// and execution semantics agree with a recorded ground truth, across
+// versions v3-v8.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+
+use gruesome::instruction::Instruction;
+use gruesome::interpreter::{ExecutionResult, Interpreter};
+use gruesome::vm::{CallFrame, Game, VM};
+
+/// Maximum local variables per call frame (mirrors `vm::CallFrame::locals`).
+const MAX_LOCALS: usize = 16;
+
+/// Size of the synthetic memory buffer test cases are replayed against.
+const MEMORY_SIZE: usize = 0x10000;
+
+/// A sparse snapshot of VM-relevant state, as recorded in a test case's
+/// `initial` or `final` object.
+#[derive(Debug, Deserialize)]
+struct TestState {
+    pc: u32,
+    #[serde(default)]
+    stack: Vec<u16>,
+    #[serde(default)]
+    locals: Vec<u16>,
+    /// Globals to patch/check, keyed by hex variable number (e.g. "10" = G00)
+    #[serde(default)]
+    globals: HashMap<String, u16>,
+    /// Sparse `[address, byte]` memory cells
+    #[serde(default)]
+    ram: Vec<(u32, u8)>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestCase {
+    name: String,
+    #[serde(default = "default_version")]
+    version: u8,
+    initial: TestState,
+    #[serde(rename = "final")]
+    expected: TestState,
+}
+
+fn default_version() -> u8 {
+    3
+}
+
+fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = env::args().collect();
+
+    let mut input_path: Option<PathBuf> = None;
+    let mut file_filter: Option<String> = None;
+    let mut case_filter: Option<usize> = None;
+    let mut expect_count: Option<usize> = None;
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--file" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --file requires a filename substring");
+                    process::exit(1);
+                }
+                file_filter = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--case" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --case requires a test index");
+                    process::exit(1);
+                }
+                case_filter = match args[i + 1].parse() {
+                    Ok(n) => Some(n),
+                    Err(_) => {
+                        eprintln!("Error: --case requires a non-negative integer");
+                        process::exit(1);
+                    }
+                };
+                i += 2;
+            }
+            "--expect-count" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --expect-count requires a test count");
+                    process::exit(1);
+                }
+                expect_count = match args[i + 1].parse() {
+                    Ok(n) => Some(n),
+                    Err(_) => {
+                        eprintln!("Error: --expect-count requires a non-negative integer");
+                        process::exit(1);
+                    }
+                };
+                i += 2;
+            }
+            "-h" | "--help" => {
+                print_usage(&args[0]);
+                process::exit(0);
+            }
+            arg if arg.starts_with('-') => {
+                eprintln!("Error: Unknown option '{}'", arg);
+                print_usage(&args[0]);
+                process::exit(1);
+            }
+            _ => {
+                if input_path.is_some() {
+                    eprintln!("Error: Multiple input paths specified");
+                    process::exit(1);
+                }
+                input_path = Some(PathBuf::from(&args[i]));
+                i += 1;
+            }
+        }
+    }
+
+    let input_path = match input_path {
+        Some(p) => p,
+        None => {
+            print_usage(&args[0]);
+            process::exit(1);
+        }
+    };
+
+    let files = match collect_test_files(&input_path, file_filter.as_deref()) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if files.is_empty() {
+        eprintln!(
+            "Error: no matching *.json.gz test files found under '{}'",
+            input_path.display()
+        );
+        process::exit(1);
+    }
+
+    let mut total = 0usize;
+    let mut failed = 0usize;
+
+    for path in &files {
+        let cases = match load_test_cases(path) {
+            Ok(cases) => cases,
+            Err(e) => {
+                eprintln!("Error: failed to load '{}': {}", path.display(), e);
+                process::exit(1);
+            }
+        };
+
+        for (index, case) in cases.iter().enumerate() {
+            if let Some(only) = case_filter {
+                if index != only {
+                    continue;
+                }
+            }
+
+            total += 1;
+            if let Err(diffs) = run_case(case) {
+                failed += 1;
+                eprintln!("FAIL {} #{} \"{}\":", path.display(), index, case.name);
+                for diff in diffs {
+                    eprintln!("  {}", diff);
+                }
+            }
+        }
+    }
+
+    println!("Ran {} test case(s), {} failed", total, failed);
+
+    if let Some(expected) = expect_count {
+        if total != expected {
+            eprintln!(
+                "Error: expected exactly {} test case(s), ran {}",
+                expected, total
+            );
+            process::exit(1);
+        }
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Find the `*.json.gz` test files to run: `path` itself if it's a file, or
+/// every `*.json.gz` directly inside it if it's a directory. `filter`, when
+/// given, keeps only files whose name contains the substring.
+fn collect_test_files(path: &Path, filter: Option<&str>) -> Result<Vec<PathBuf>, String> {
+    let matches_filter = |p: &Path| -> bool {
+        match filter {
+            Some(substr) => p
+                .file_name()
+                .map(|n| n.to_string_lossy().contains(substr))
+                .unwrap_or(false),
+            None => true,
+        }
+    };
+
+    if path.is_file() {
+        return Ok(if matches_filter(path) {
+            vec![path.to_path_buf()]
+        } else {
+            vec![]
+        });
+    }
+
+    let mut files = Vec::new();
+    let entries = std::fs::read_dir(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    for entry in entries {
+        let entry_path = entry.map_err(|e| e.to_string())?.path();
+        let is_json_gz = entry_path
+            .file_name()
+            .map(|n| n.to_string_lossy().ends_with(".json.gz"))
+            .unwrap_or(false);
+        if is_json_gz && matches_filter(&entry_path) {
+            files.push(entry_path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Decompress and parse a single `*.json.gz` test file into its test cases.
+fn load_test_cases(path: &Path) -> Result<Vec<TestCase>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let mut decoder = GzDecoder::new(file);
+    let mut json = String::new();
+    decoder
+        .read_to_string(&mut json)
+        .map_err(|e| format!("gzip decode failed: {}", e))?;
+    serde_json::from_str(&json).map_err(|e| format!("JSON parse failed: {}", e))
+}
+
+/// Build a VM patched with `case.initial`, single-step exactly one
+/// instruction through `Interpreter`, and diff the resulting state against
+/// `case.expected`.
+fn run_case(case: &TestCase) -> Result<(), Vec<String>> {
+    let mut vm = build_vm(case)?;
+
+    let instruction = Instruction::decode(&vm.game.memory, vm.pc as usize, case.version)
+        .map_err(|e| vec![format!("decode failed at PC 0x{:04x}: {}", vm.pc, e)])?;
+    vm.pc += instruction.size as u32;
+
+    let mut interp = Interpreter::new(vm);
+    match interp.execute_instruction(&instruction) {
+        Ok(ExecutionResult::Error(e)) => return Err(vec![format!("execution error: {}", e)]),
+        Err(e) => return Err(vec![format!("execution error: {}", e)]),
+        Ok(_) => {}
+    }
+
+    diff_state(case, &interp.vm)
+}
+
+/// Build a `VM` via `Game::from_memory`/`VM::new` with `case.initial` patched
+/// into its memory, globals, stack, call-frame locals, and PC. Returns a
+/// per-case diagnostic (rather than panicking) if `case.initial` is
+/// malformed or out of range, mirroring `diff_state`'s handling of bad data.
+fn build_vm(case: &TestCase) -> Result<VM, Vec<String>> {
+    let mut memory = vec![0u8; MEMORY_SIZE];
+    memory[0x00] = case.version;
+    memory[0x0c] = 0x01; // global variable table at 0x0100
+    memory[0x0d] = 0x00;
+    memory[0x0e] = 0xf0; // base of static memory, high enough that most writes succeed
+    memory[0x0f] = 0x00;
+
+    let mut diffs = Vec::new();
+
+    for &(addr, byte) in &case.initial.ram {
+        match memory.get_mut(addr as usize) {
+            Some(cell) => *cell = byte,
+            None => diffs.push(format!(
+                "initial.ram address 0x{:04x} is out of range (memory size 0x{:04x})",
+                addr, MEMORY_SIZE
+            )),
+        }
+    }
+
+    if case.initial.locals.len() > MAX_LOCALS {
+        diffs.push(format!(
+            "initial.locals has {} entries, more than the max of {}",
+            case.initial.locals.len(),
+            MAX_LOCALS
+        ));
+    }
+
+    if !diffs.is_empty() {
+        return Err(diffs);
+    }
+
+    let game = Game::from_memory(memory).expect("conformance harness memory buffer too small");
+    let mut vm = VM::new(game);
+    vm.pc = case.initial.pc;
+    vm.stack = case.initial.stack.clone();
+
+    let mut locals = [0u16; MAX_LOCALS];
+    for (i, &value) in case.initial.locals.iter().enumerate() {
+        locals[i] = value;
+    }
+    vm.call_stack = vec![CallFrame {
+        return_pc: 0,
+        return_store: None,
+        num_locals: case.initial.locals.len() as u8,
+        locals,
+        stack_base: 0,
+    }];
+
+    for (global, &value) in &case.initial.globals {
+        let var = match u8::from_str_radix(global, 16) {
+            Ok(v) => v,
+            Err(_) => {
+                diffs.push(format!(
+                    "initial.globals key '{}' is not a hex byte",
+                    global
+                ));
+                continue;
+            }
+        };
+        if let Err(e) = vm.write_global(var, value) {
+            diffs.push(format!("initial global {}: {}", global, e));
+        }
+    }
+
+    if diffs.is_empty() {
+        Ok(vm)
+    } else {
+        Err(diffs)
+    }
+}
+
+/// Compare every field `case.expected` records against the VM's actual
+/// post-execution state, returning a human-readable diff per mismatch.
+fn diff_state(case: &TestCase, vm: &VM) -> Result<(), Vec<String>> {
+    let mut diffs = Vec::new();
+
+    if vm.pc != case.expected.pc {
+        diffs.push(format!(
+            "pc: expected 0x{:04x}, got 0x{:04x}",
+            case.expected.pc, vm.pc
+        ));
+    }
+
+    if vm.stack != case.expected.stack {
+        diffs.push(format!(
+            "stack: expected {:?}, got {:?}",
+            case.expected.stack, vm.stack
+        ));
+    }
+
+    let actual_locals: Vec<u16> = vm
+        .call_stack
+        .last()
+        .map(|frame| frame.locals[..frame.num_locals as usize].to_vec())
+        .unwrap_or_default();
+    if actual_locals != case.expected.locals {
+        diffs.push(format!(
+            "locals: expected {:?}, got {:?}",
+            case.expected.locals, actual_locals
+        ));
+    }
+
+    for (global, &expected_value) in &case.expected.globals {
+        let var = match u8::from_str_radix(global, 16) {
+            Ok(v) => v,
+            Err(_) => {
+                diffs.push(format!("final.globals key '{}' is not a hex byte", global));
+                continue;
+            }
+        };
+        match vm.read_global(var) {
+            Ok(actual_value) if actual_value != expected_value => {
+                diffs.push(format!(
+                    "global {}: expected 0x{:04x}, got 0x{:04x}",
+                    global, expected_value, actual_value
+                ));
+            }
+            Err(e) => diffs.push(format!("global {}: {}", global, e)),
+            _ => {}
+        }
+    }
+
+    for &(addr, expected_byte) in &case.expected.ram {
+        let actual_byte = vm.read_byte(addr);
+        if actual_byte != expected_byte {
+            diffs.push(format!(
+                "memory[0x{:04x}]: expected 0x{:02x}, got 0x{:02x}",
+                addr, expected_byte, actual_byte
+            ));
+        }
+    }
+
+    if diffs.is_empty() {
+        Ok(())
+    } else {
+        Err(diffs)
+    }
+}
+
+fn print_usage(program_name: &str) {
+    println!(
+        "Usage: {} <test-dir-or-file.json.gz> [options]",
+        program_name
+    );
+    println!();
+    println!("Options:");
+    println!("  --file <substring>     Only run test files whose name contains <substring>");
+    println!("  --case <n>             Only run the n-th (0-based) case in each file");
+    println!("  --expect-count <n>     Assert that exactly <n> test case(s) were run in total");
+    println!("  -h, --help             Show this help message");
+    println!();
+    println!("Each test file is a gzipped JSON array of cases shaped like:");
+    println!("  {{");
+    println!("    \"name\": \"add 1 2 -> sp\",");
+    println!("    \"version\": 3,");
+    println!("    \"initial\": {{ \"pc\": 1024, \"stack\": [],  \"locals\": [], \"globals\": {{}}, \"ram\": [[1024, 20], [1025, 1], [1026, 2], [1027, 0]] }},");
+    println!("    \"final\":   {{ \"pc\": 1028, \"stack\": [3], \"locals\": [], \"globals\": {{}}, \"ram\": [] }}");
+    println!("  }}");
+}
@@ -1,5 +1,6 @@
 use gruesome::disasm_txd::TxdDisassembler;
 use gruesome::instruction::{Instruction, InstructionForm};
+use gruesome::trap::Trap;
 use gruesome::vm::Game;
 use log::info;
 use std::collections::HashSet;
@@ -151,8 +152,8 @@ fn validate_routine(memory: &[u8], addr: u32, version: u8) -> ValidationResult {
                 pc += inst.size;
             }
             Err(e) => {
-                // Check if error is the Long 0x00 we fixed
-                if e.contains("Invalid Long form opcode 0x00") {
+                // Check if the fault is the Long 0x00 opcode we fixed
+                if matches!(Trap::classify(&e), Trap::BadOpcode { opcode: 0x00, .. }) {
                     return ValidationResult::Invalid("Hits invalid Long opcode 0x00".to_string());
                 }
                 return ValidationResult::Invalid(format!("Decode error: {e}"));
@@ -1,7 +1,49 @@
 use crate::instruction::Instruction;
 use crate::interpreter::Interpreter;
+use crate::symbols::{format_instruction_with_symbols, SymbolTable};
+use crate::trace::{RingBufferSink, TraceFilter, Tracer};
 use crate::vm::VM;
+use reedline::{DefaultPrompt, DefaultPromptSegment, Reedline, Signal};
+use std::cell::RefCell;
 use std::io::{self, Write};
+use std::rc::Rc;
+
+/// A location a watchpoint observes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WatchTarget {
+    /// A global variable, numbered 0-239 (G00-Gef in the usual notation).
+    Global(u8),
+    /// A single byte of memory.
+    Memory(u32),
+    /// An object property, read the same way `get_prop` would.
+    ObjectProperty(u16, u8),
+}
+
+impl std::fmt::Display for WatchTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatchTarget::Global(var) => write!(f, "G{var:02x}"),
+            WatchTarget::Memory(addr) => write!(f, "mem 0x{addr:04x}"),
+            WatchTarget::ObjectProperty(obj, prop) => write!(f, "obj {obj} prop {prop}"),
+        }
+    }
+}
+
+/// When a watchpoint should fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WatchCondition {
+    /// Fire the instant the target's value differs from its last-seen value.
+    AnyChange,
+    /// Fire only when the target's value becomes exactly this value.
+    Equals(u16),
+}
+
+/// A single watched target plus the last value it was seen holding.
+struct Watchpoint {
+    target: WatchTarget,
+    condition: WatchCondition,
+    last_value: u16,
+}
 
 /// A debugger for step-by-step execution and disassembly
 pub struct Debugger {
@@ -9,12 +51,23 @@ pub struct Debugger {
     pub interpreter: Interpreter,
     /// Breakpoints (PC addresses)
     breakpoints: Vec<u32>,
+    /// Data watchpoints, checked after every executed instruction (see [`Debugger::poll_watchpoints`])
+    watchpoints: Vec<Watchpoint>,
+    /// Set by [`Debugger::step`] when the instruction it just ran caused a watchpoint to
+    /// fire, so callers like [`Debugger::run_repl`]'s continue loop know to stop.
+    watch_fired: bool,
     /// Single-step mode enabled
     single_step: bool,
     /// Instruction history
     history: Vec<(u32, String)>,
     /// Maximum history size
     max_history: usize,
+    /// Ring buffer backing the installed tracer, if any (shared with the `Tracer` so
+    /// its contents can be dumped after a breakpoint or error via [`Debugger::dump_trace`])
+    trace_ring: Option<Rc<RefCell<RingBufferSink>>>,
+    /// Names for routines/objects/labels, used to render disassembly; empty until
+    /// [`Debugger::load_symbols`] is called, in which case everything just renders as hex.
+    symbols: SymbolTable,
 }
 
 impl Debugger {
@@ -23,12 +76,24 @@ impl Debugger {
         Debugger {
             interpreter: Interpreter::new(vm),
             breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+            watch_fired: false,
             single_step: false,
             history: Vec::new(),
             max_history: 100,
+            trace_ring: None,
+            symbols: SymbolTable::new(),
         }
     }
 
+    /// Load a symbol map from disk, replacing any previously loaded symbols, so
+    /// subsequent disassembly renders calls, object operands, and branch targets as
+    /// names instead of bare hex.
+    pub fn load_symbols(&mut self, path: &str) -> Result<(), String> {
+        self.symbols = SymbolTable::load_from_file(path)?;
+        Ok(())
+    }
+
     /// Enable or disable single-step mode
     pub fn set_single_step(&mut self, enabled: bool) {
         self.single_step = enabled;
@@ -59,6 +124,133 @@ impl Debugger {
         }
     }
 
+    /// Read the current value of a watch target.
+    fn read_watch_target(&self, target: &WatchTarget) -> Result<u16, String> {
+        match *target {
+            WatchTarget::Global(index) => match index.checked_add(0x10) {
+                Some(var) => self.interpreter.vm.read_global(var),
+                None => Err(format!("Invalid global: G{index:02x}")),
+            },
+            WatchTarget::Memory(addr) => Ok(self.interpreter.vm.read_byte(addr) as u16),
+            WatchTarget::ObjectProperty(obj, prop) => self.interpreter.vm.get_property(obj, prop),
+        }
+    }
+
+    /// Add a watchpoint on `target`, firing per `condition`. Captures the target's
+    /// current value as the baseline, so an `AnyChange` watch doesn't fire on the next
+    /// poll just because nothing has been read yet.
+    pub fn add_watch(
+        &mut self,
+        target: WatchTarget,
+        condition: WatchCondition,
+    ) -> Result<(), String> {
+        let last_value = self.read_watch_target(&target)?;
+        println!("Watching {target} (current value {last_value})");
+        self.watchpoints.push(Watchpoint {
+            target,
+            condition,
+            last_value,
+        });
+        Ok(())
+    }
+
+    /// Remove the watchpoint at `index` (as shown by [`Debugger::list_watchpoints`]).
+    pub fn remove_watch(&mut self, index: usize) {
+        if index < self.watchpoints.len() {
+            let wp = self.watchpoints.remove(index);
+            println!("Removed watchpoint on {}", wp.target);
+        } else {
+            println!("No watchpoint at index {index}");
+        }
+    }
+
+    /// List all watchpoints with their last-seen value.
+    pub fn list_watchpoints(&self) {
+        if self.watchpoints.is_empty() {
+            println!("No watchpoints set.");
+        } else {
+            println!("Watchpoints:");
+            for (i, wp) in self.watchpoints.iter().enumerate() {
+                let condition = match wp.condition {
+                    WatchCondition::AnyChange => "on any change".to_string(),
+                    WatchCondition::Equals(v) => format!("when value == {v}"),
+                };
+                println!(
+                    "  [{}] {} ({condition}), last value {}",
+                    i, wp.target, wp.last_value
+                );
+            }
+        }
+    }
+
+    /// Re-read every watchpoint's target, reporting (and updating the baseline for) any
+    /// whose value now satisfies its condition. Called after every executed instruction
+    /// with that instruction's address `pc`, so the PC/instruction printed alongside a
+    /// hit is the one that caused the write. Returns whether any watchpoint fired.
+    fn poll_watchpoints(&mut self, pc: u32) -> bool {
+        let mut hits = Vec::new();
+        for i in 0..self.watchpoints.len() {
+            let target = self.watchpoints[i].target.clone();
+            let new_value = match self.read_watch_target(&target) {
+                Ok(v) => v,
+                Err(_) => continue, // target no longer addressable; leave baseline as-is
+            };
+            let wp = &mut self.watchpoints[i];
+            let fired = match wp.condition {
+                WatchCondition::AnyChange => new_value != wp.last_value,
+                WatchCondition::Equals(value) => new_value == value && wp.last_value != value,
+            };
+            if fired {
+                hits.push((wp.target.clone(), wp.last_value, new_value));
+            }
+            wp.last_value = new_value;
+        }
+
+        for (target, old, new) in &hits {
+            println!("Watchpoint fired: {target} changed from {old} to {new} at PC 0x{pc:05x}");
+            if let Ok(disasm) = self.disassemble_at(pc) {
+                println!("  Written by: {disasm}");
+            }
+        }
+
+        !hits.is_empty()
+    }
+
+    /// Install a structured execution tracer on the underlying interpreter, filtered by
+    /// `filter` and retaining the last `ring_capacity` accepted records for post-mortem
+    /// dumping via [`Debugger::dump_trace`].
+    pub fn set_trace_filter(&mut self, filter: TraceFilter, ring_capacity: usize) {
+        let ring = Rc::new(RefCell::new(RingBufferSink::new(ring_capacity)));
+        self.interpreter
+            .set_tracer(Tracer::new(filter, Box::new(ring.clone())));
+        self.trace_ring = Some(ring);
+    }
+
+    /// Disable tracing.
+    pub fn clear_trace_filter(&mut self) {
+        self.interpreter.clear_tracer();
+        self.trace_ring = None;
+    }
+
+    /// Print the instructions retained by the installed tracer's ring buffer, oldest
+    /// first. Intended for post-mortem dumping when a breakpoint or error fires.
+    pub fn dump_trace(&self) {
+        match &self.trace_ring {
+            Some(ring) => {
+                let ring = ring.borrow();
+                if ring.is_empty() {
+                    println!("Trace ring buffer is empty.");
+                } else {
+                    println!("Trace history ({} records):", ring.len());
+                    for record in ring.records() {
+                        println!("  {record}");
+                    }
+                }
+            }
+            None => println!("No tracer installed. Use set_trace_filter() first."),
+        }
+    }
+
     /// List all breakpoints
     pub fn list_breakpoints(&self) {
         if self.breakpoints.is_empty() {
@@ -79,12 +271,25 @@ impl Debugger {
 
     /// Disassemble instruction at given PC
     pub fn disassemble_at(&self, pc: u32) -> Result<String, String> {
-        match Instruction::decode(&self.interpreter.vm.game.memory, pc as usize, self.interpreter.vm.game.header.version) {
+        match Instruction::decode(
+            &self.interpreter.vm.game.memory,
+            pc as usize,
+            self.interpreter.vm.game.header.version,
+        ) {
             Ok(inst) => {
-                let formatted = inst.format_with_version(self.interpreter.vm.game.header.version);
+                let formatted = format_instruction_with_symbols(
+                    pc,
+                    &inst,
+                    self.interpreter.vm.game.header.version,
+                    &self.interpreter.vm.game.header,
+                    &self.symbols,
+                );
                 Ok(format!("{:05x}: {}", pc, formatted))
             }
-            Err(e) => Err(format!("Failed to decode instruction at 0x{:05x}: {}", pc, e))
+            Err(e) => Err(format!(
+                "Failed to decode instruction at 0x{:05x}: {}",
+                pc, e
+            )),
         }
     }
 
@@ -92,13 +297,17 @@ impl Debugger {
     pub fn disassemble_range(&self, start_pc: u32, count: usize) -> Vec<String> {
         let mut results = Vec::new();
         let mut pc = start_pc;
-        
+
         for _ in 0..count {
             match self.disassemble_at(pc) {
                 Ok(line) => {
                     results.push(line);
                     // Try to get the instruction size to advance PC
-                    if let Ok(inst) = Instruction::decode(&self.interpreter.vm.game.memory, pc as usize, self.interpreter.vm.game.header.version) {
+                    if let Ok(inst) = Instruction::decode(
+                        &self.interpreter.vm.game.memory,
+                        pc as usize,
+                        self.interpreter.vm.game.header.version,
+                    ) {
                         pc += inst.size as u32;
                     } else {
                         pc += 1; // Fallback
@@ -110,29 +319,44 @@ impl Debugger {
                 }
             }
         }
-        
+
         results
     }
 
+    /// Reachability disassembly of the whole game (`disas reach`): follows every call,
+    /// branch, and jump from the game's start address instead of a fixed linear range.
+    /// This module tracks no dictionary/object table, so it has no extra entry points to
+    /// seed beyond the start address - routines reachable only via verb/action dispatch
+    /// tables won't show up.
+    pub fn disassemble_reachable(&self) -> Result<String, String> {
+        crate::disassembler::Disassembler::new(&self.interpreter.vm.game.memory).disassemble_program(
+            &self.interpreter.vm.game.header,
+            &[],
+            &self.symbols,
+        )
+    }
+
     /// Show current VM state
     pub fn show_state(&self) {
         println!("=== VM State ===");
         println!("PC: 0x{:05x}", self.interpreter.vm.pc);
         println!("Stack size: {}", self.interpreter.vm.stack.len());
         println!("Call stack depth: {}", self.interpreter.vm.call_stack.len());
-        
+
         // Show current instruction
         if let Ok(disasm) = self.disassemble_current() {
             println!("Current: {}", disasm);
         }
-        
+
         // Show call stack
         if !self.interpreter.vm.call_stack.is_empty() {
             println!("\nCall Stack:");
             for (i, frame) in self.interpreter.vm.call_stack.iter().enumerate() {
-                println!("  [{}] Return PC: 0x{:05x}, Locals: {}", 
-                        i, frame.return_pc, frame.num_locals);
-                
+                println!(
+                    "  [{}] Return PC: 0x{:05x}, Locals: {}",
+                    i, frame.return_pc, frame.num_locals
+                );
+
                 // Show locals for current frame
                 if i == self.interpreter.vm.call_stack.len() - 1 {
                     for j in 0..frame.num_locals as usize {
@@ -141,7 +365,7 @@ impl Debugger {
                 }
             }
         }
-        
+
         // Show recent stack values
         if !self.interpreter.vm.stack.is_empty() {
             println!("\nStack (top 5):");
@@ -164,29 +388,46 @@ impl Debugger {
     /// Execute a single instruction
     pub fn step(&mut self) -> Result<bool, String> {
         let pc = self.interpreter.vm.pc;
-        
+
         // Decode and record instruction for history
-        if let Ok(inst) = Instruction::decode(&self.interpreter.vm.game.memory, pc as usize, self.interpreter.vm.game.header.version) {
-            let formatted = inst.format_with_version(self.interpreter.vm.game.header.version);
-            
+        if let Ok(inst) = Instruction::decode(
+            &self.interpreter.vm.game.memory,
+            pc as usize,
+            self.interpreter.vm.game.header.version,
+        ) {
+            let formatted = format_instruction_with_symbols(
+                pc,
+                &inst,
+                self.interpreter.vm.game.header.version,
+                &self.interpreter.vm.game.header,
+                &self.symbols,
+            );
+
             // Add to history
             self.history.push((pc, formatted.clone()));
             if self.history.len() > self.max_history {
                 self.history.remove(0);
             }
-            
+
             // Show instruction if in single-step mode
             if self.single_step {
                 println!("{:05x}: {}", pc, formatted);
             }
-            
+
+            // Feed the installed tracer, same as Interpreter::run_with_limit, so
+            // `trace`/`dump_trace` also see instructions executed via single-stepping.
+            self.interpreter.offer_trace(pc, &inst);
+
             // Update PC
             self.interpreter.vm.pc += inst.size as u32;
-            
+
             // Execute instruction
             match self.interpreter.execute_instruction(&inst) {
-                Ok(_) => Ok(true),
-                Err(e) => Err(format!("Execution error at 0x{:05x}: {}", pc, e))
+                Ok(_) => {
+                    self.watch_fired = self.poll_watchpoints(pc);
+                    Ok(true)
+                }
+                Err(e) => Err(format!("Execution error at 0x{:05x}: {}", pc, e)),
             }
         } else {
             Err(format!("Failed to decode instruction at 0x{:05x}", pc))
@@ -197,24 +438,26 @@ impl Debugger {
     pub fn run(&mut self) -> Result<(), String> {
         loop {
             let pc = self.interpreter.vm.pc;
-            
+
             // Check for breakpoints
             if self.breakpoints.contains(&pc) {
                 println!("Hit breakpoint at 0x{:05x}", pc);
                 self.set_single_step(true);
             }
-            
+
             // Handle single-step mode
             if self.single_step {
                 self.show_state();
-                
+
                 print!("(debug) ");
                 io::stdout().flush().ok();
-                
+
                 let mut input = String::new();
-                io::stdin().read_line(&mut input).map_err(|e| format!("Input error: {}", e))?;
+                io::stdin()
+                    .read_line(&mut input)
+                    .map_err(|e| format!("Input error: {}", e))?;
                 let input = input.trim();
-                
+
                 match input {
                     "n" | "next" | "" => {
                         // Step one instruction
@@ -264,7 +507,9 @@ impl Debugger {
                         continue;
                     }
                     _ => {
-                        println!("Commands: n(ext), c(ontinue), s(tate), h(istory), d(isasm), q(uit)");
+                        println!(
+                            "Commands: n(ext), c(ontinue), s(tate), h(istory), d(isasm), q(uit)"
+                        );
                         println!("         b <addr> (breakpoint), rb <addr> (remove), bl (list)");
                         continue;
                     }
@@ -274,7 +519,243 @@ impl Debugger {
                 if let Err(e) = self.step() {
                     return Err(e);
                 }
+                if self.watch_fired {
+                    self.set_single_step(true);
+                }
             }
         }
     }
+
+    /// Run an interactive REPL driven by a `reedline` line editor, replacing the
+    /// hard-coded `if pc == 0x....` breakpoints scattered across the one-off debug
+    /// binaries with a persistent, stateful front end over the operations they already
+    /// call (`Instruction::decode`, `format_with_version`, `read_variable`,
+    /// `execute_instruction`, ...).
+    pub fn run_repl(&mut self) -> Result<(), String> {
+        let mut editor = Reedline::create();
+        let prompt = DefaultPrompt::new(
+            DefaultPromptSegment::Basic("debug".to_string()),
+            DefaultPromptSegment::Empty,
+        );
+
+        println!("Interactive debugger. Type 'help' for a list of commands.");
+
+        loop {
+            let pc = self.interpreter.vm.pc;
+            if self.breakpoints.contains(&pc) {
+                println!("Hit breakpoint at 0x{:05x}", pc);
+            }
+
+            let line = match editor.read_line(&prompt) {
+                Ok(Signal::Success(line)) => line,
+                Ok(Signal::CtrlC) | Ok(Signal::CtrlD) => return Ok(()),
+                Err(e) => return Err(format!("Line editor error: {e}")),
+            };
+
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                ["s"] | ["si"] | ["step"] => {
+                    if let Err(e) = self.step() {
+                        println!("{e}");
+                    }
+                }
+                ["c"] | ["continue"] => {
+                    if let Err(e) = self.continue_to_breakpoint() {
+                        println!("{e}");
+                    }
+                }
+                ["break", addr] | ["b", addr] => match parse_addr(addr) {
+                    Ok(addr) => self.add_breakpoint(addr),
+                    Err(e) => println!("{e}"),
+                },
+                ["clear", addr] | ["rb", addr] => match parse_addr(addr) {
+                    Ok(addr) => self.remove_breakpoint(addr),
+                    Err(e) => println!("{e}"),
+                },
+                ["bl"] | ["breakpoints"] => self.list_breakpoints(),
+                ["watch", rest @ ..] => match parse_watch_args(rest) {
+                    Ok((target, condition)) => {
+                        if let Err(e) = self.add_watch(target, condition) {
+                            println!("{e}");
+                        }
+                    }
+                    Err(e) => println!("{e}"),
+                },
+                ["watches"] | ["wl"] => self.list_watchpoints(),
+                ["unwatch", index] => match index.parse::<usize>() {
+                    Ok(index) => self.remove_watch(index),
+                    Err(_) => println!("Invalid watchpoint index: {index}"),
+                },
+                ["disas"] => {
+                    for line in self.disassemble_range(pc, 10) {
+                        println!("{line}");
+                    }
+                }
+                ["disas", "reach"] => match self.disassemble_reachable() {
+                    Ok(text) => println!("{text}"),
+                    Err(e) => println!("{e}"),
+                },
+                ["disas", count] => match count.parse::<usize>() {
+                    Ok(count) => {
+                        for line in self.disassemble_range(pc, count) {
+                            println!("{line}");
+                        }
+                    }
+                    Err(_) => println!("Invalid instruction count: {count}"),
+                },
+                ["bt"] | ["stack"] => self.show_state(),
+                ["g", var] => match var.parse::<u8>() {
+                    Ok(var) => match self.interpreter.vm.read_global(var) {
+                        Ok(value) => println!("G{var:02} = {value} (0x{value:04x})"),
+                        Err(e) => println!("{e}"),
+                    },
+                    Err(_) => println!("Invalid global number: {var}"),
+                },
+                ["g", var, "=", value] => {
+                    match (var.parse::<u8>(), parse_addr(value).map(|v| v as u16)) {
+                        (Ok(var), Ok(value)) => {
+                            match self.interpreter.vm.write_global(var, value) {
+                                Ok(()) => println!("G{var:02} = {value} (0x{value:04x})"),
+                                Err(e) => println!("{e}"),
+                            }
+                        }
+                        _ => println!("Usage: g <number> = <value>"),
+                    }
+                }
+                ["m", addr] => match parse_addr(addr) {
+                    Ok(addr) => println!(
+                        "0x{:05x}: 0x{:02x}",
+                        addr,
+                        self.interpreter.vm.read_byte(addr)
+                    ),
+                    Err(e) => println!("{e}"),
+                },
+                ["m", addr, "=", value] => match (parse_addr(addr), parse_addr(value)) {
+                    (Ok(addr), Ok(value)) => {
+                        match self.interpreter.vm.write_byte(addr, value as u8) {
+                            Ok(()) => println!("0x{:05x}: 0x{:02x}", addr, value as u8),
+                            Err(e) => println!("{e}"),
+                        }
+                    }
+                    _ => println!("Usage: m <addr> = <value>"),
+                },
+                ["h"] | ["history"] => self.show_history(10),
+                ["symbols", path] => match self.load_symbols(path) {
+                    Ok(()) => println!("Loaded symbol map from {path}"),
+                    Err(e) => println!("{e}"),
+                },
+                ["trace"] => self.dump_trace(),
+                ["protect"] => {
+                    self.interpreter.vm.set_strict_memory_protection(true);
+                    println!("Strict memory protection enabled: writes to static/high memory or a protected range now abort instead of being silently dropped.");
+                }
+                ["protect", "off"] => {
+                    self.interpreter.vm.set_strict_memory_protection(false);
+                    println!("Strict memory protection disabled.");
+                }
+                ["protect", start, end] => match (parse_addr(start), parse_addr(end)) {
+                    (Ok(start), Ok(end)) => {
+                        self.interpreter.vm.protect_range(start, end);
+                        println!("Protected range 0x{start:05x}..0x{end:05x} added.");
+                    }
+                    _ => println!("Usage: protect <start> <end>"),
+                },
+                ["?"] | ["help"] => print_repl_help(),
+                ["q"] | ["quit"] => return Ok(()),
+                _ => println!("Unknown command '{line}'. Type 'help' for a list of commands."),
+            }
+        }
+    }
+
+    /// Single-step until a breakpoint is hit or execution ends, without the per-step
+    /// printing `run()`'s single-step mode does.
+    fn continue_to_breakpoint(&mut self) -> Result<(), String> {
+        loop {
+            self.step()?;
+            if self.breakpoints.contains(&self.interpreter.vm.pc) || self.watch_fired {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Parse an address/value as hex, accepting an optional `0x`/`0X` prefix (breakpoint
+/// and memory commands in this REPL are always hex, matching disassembly output).
+fn parse_addr(s: &str) -> Result<u32, String> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    u32::from_str_radix(digits, 16).map_err(|_| format!("Invalid hex address: {s}"))
+}
+
+/// Parse the arguments to the REPL's `watch` command:
+/// `G<hex>`, `mem <addr>`, or `obj <num> prop <num>`, each optionally followed by
+/// `== <value>` to watch for a specific value instead of any change.
+fn parse_watch_args(words: &[&str]) -> Result<(WatchTarget, WatchCondition), String> {
+    const USAGE: &str =
+        "Usage: watch G<hex> | watch mem <addr> | watch obj <num> prop <num> [== <value>]";
+    match words {
+        ["mem", addr, rest @ ..] => {
+            let addr = parse_addr(addr)?;
+            let condition = parse_watch_condition(rest)?;
+            Ok((WatchTarget::Memory(addr), condition))
+        }
+        ["obj", obj, "prop", prop, rest @ ..] => {
+            let obj: u16 = obj
+                .parse()
+                .map_err(|_| format!("Invalid object number: {obj}"))?;
+            let prop: u8 = prop
+                .parse()
+                .map_err(|_| format!("Invalid property number: {prop}"))?;
+            let condition = parse_watch_condition(rest)?;
+            Ok((WatchTarget::ObjectProperty(obj, prop), condition))
+        }
+        [global, rest @ ..] if global.starts_with(['g', 'G']) => {
+            let var = u8::from_str_radix(&global[1..], 16)
+                .map_err(|_| format!("Invalid global: {global}"))?;
+            let condition = parse_watch_condition(rest)?;
+            Ok((WatchTarget::Global(var), condition))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Parse the optional `== <value>` (or `= <value>`) suffix of a `watch` command.
+fn parse_watch_condition(words: &[&str]) -> Result<WatchCondition, String> {
+    match words {
+        [] => Ok(WatchCondition::AnyChange),
+        ["==", value] | ["=", value] => parse_addr(value).map(|v| WatchCondition::Equals(v as u16)),
+        _ => Err("Usage: ... [== <value>]".to_string()),
+    }
+}
+
+fn print_repl_help() {
+    println!("Commands:");
+    println!("  s, si, step        Execute one instruction");
+    println!("  c, continue        Run until the next breakpoint or watchpoint");
+    println!("  break <addr>       Set a breakpoint at a PC or routine-entry address");
+    println!("  clear <addr>       Remove a breakpoint");
+    println!("  bl, breakpoints    List breakpoints");
+    println!("  watch G<hex>              Watch a global for any change");
+    println!("  watch mem <addr>          Watch a memory byte for any change");
+    println!("  watch obj <n> prop <p>    Watch an object property for any change");
+    println!("  ... [== <value>]          Fire only when the value becomes <value>");
+    println!("  watches, wl        List watchpoints");
+    println!("  unwatch <index>    Remove a watchpoint");
+    println!("  disas [count]      Disassemble starting at the current PC (default 10)");
+    println!("  disas reach        Reachability disassembly of the whole game from start");
+    println!("  bt, stack          Show PC, call stack, and the evaluation stack");
+    println!("  g <n>              Read global variable n");
+    println!("  g <n> = <value>    Write global variable n");
+    println!("  m <addr>           Read a byte of memory");
+    println!("  m <addr> = <value> Write a byte of memory");
+    println!("  h, history         Show recent instruction history");
+    println!("  symbols <path>     Load a symbol map; disassembly renders known names");
+    println!("  trace              Dump the installed tracer's ring buffer");
+    println!("  protect            Enable strict memory protection (flag writes into static/high memory)");
+    println!("  protect off        Disable strict memory protection");
+    println!("  protect <a> <b>    Mark [a, b) read-only in addition to the static/high boundary");
+    println!("  q, quit            Exit the debugger");
 }
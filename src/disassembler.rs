@@ -1,4 +1,8 @@
+use crate::header::Header;
 use crate::instruction::{Instruction, InstructionForm, OperandCount, OperandType};
+use crate::opcode_tables::get_instruction_name;
+use crate::symbols::{format_instruction_with_symbols, SymbolTable};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Write;
 
 pub struct Disassembler<'a> {
@@ -9,37 +13,37 @@ impl<'a> Disassembler<'a> {
     pub fn new(memory: &'a [u8]) -> Self {
         Disassembler { memory }
     }
-    
+
     /// Check if a given address might be the start of a routine
     /// Returns (is_routine, code_start_offset) where code_start_offset is where actual code begins
     fn check_routine_header(&self, addr: usize, force: bool) -> (bool, usize) {
         if addr >= self.memory.len() {
             return (false, 0);
         }
-        
+
         let num_locals = self.memory[addr];
-        
+
         // Z-machine routines can have 0-15 locals
         if num_locals > 15 {
             return (false, 0);
         }
-        
+
         // For version 1-4, each local has a 2-byte default value
         // For version 5+, locals start at 0 (no defaults stored)
         // We'll assume version 3 for now (Zork 1)
         let header_size = 1 + (num_locals as usize * 2);
-        
+
         // Make sure we have enough bytes for the header
         if addr + header_size > self.memory.len() {
             return (false, 0);
         }
-        
+
         // If force is true, assume it's a routine (used when explicitly disassembling a routine)
         // Otherwise, we need better heuristics to avoid false positives
         if force {
             return (true, header_size);
         }
-        
+
         // For automatic detection, be more conservative
         // We'll only treat it as a routine if explicitly told to
         (false, 0)
@@ -49,33 +53,48 @@ impl<'a> Disassembler<'a> {
     fn format_routine_header(&self, addr: usize) -> String {
         let mut output = String::new();
         let num_locals = self.memory[addr];
-        
+
         writeln!(&mut output, "\n{:#06x}: === ROUTINE START ===", addr).unwrap();
-        writeln!(&mut output, "{:#06x}: {:02x}              locals: {}", 
-                 addr, num_locals, num_locals).unwrap();
-        
+        writeln!(
+            &mut output,
+            "{:#06x}: {:02x}              locals: {}",
+            addr, num_locals, num_locals
+        )
+        .unwrap();
+
         // Display default values for locals
         let mut offset = 1;
         for i in 0..num_locals {
             if addr + offset + 1 < self.memory.len() {
-                let default_val = ((self.memory[addr + offset] as u16) << 8) | 
-                                  (self.memory[addr + offset + 1] as u16);
-                writeln!(&mut output, "{:#06x}: {:02x} {:02x}           local[{}] = {:#06x} ({})", 
-                         addr + offset, 
-                         self.memory[addr + offset], 
-                         self.memory[addr + offset + 1],
-                         i, default_val, default_val).unwrap();
+                let default_val = ((self.memory[addr + offset] as u16) << 8)
+                    | (self.memory[addr + offset + 1] as u16);
+                writeln!(
+                    &mut output,
+                    "{:#06x}: {:02x} {:02x}           local[{}] = {:#06x} ({})",
+                    addr + offset,
+                    self.memory[addr + offset],
+                    self.memory[addr + offset + 1],
+                    i,
+                    default_val,
+                    default_val
+                )
+                .unwrap();
                 offset += 2;
             }
         }
-        
+
         writeln!(&mut output, "{:#06x}: === CODE START ===", addr + offset).unwrap();
         output
     }
 
     /// Disassemble instructions starting from a given PC address
     /// Returns a formatted string containing the disassembly
-    pub fn disassemble(&self, start_pc: usize, count: Option<usize>, byte_limit: Option<usize>) -> Result<String, String> {
+    pub fn disassemble(
+        &self,
+        start_pc: usize,
+        count: Option<usize>,
+        byte_limit: Option<usize>,
+    ) -> Result<String, String> {
         let mut output = String::new();
         let mut pc = start_pc;
         let mut instructions_decoded = 0;
@@ -91,7 +110,7 @@ impl<'a> Disassembler<'a> {
                     break;
                 }
             }
-            
+
             if let Some(max_bytes) = byte_limit {
                 if pc - start_byte >= max_bytes {
                     break;
@@ -114,12 +133,12 @@ impl<'a> Disassembler<'a> {
             }
 
             // Decode instruction
-            match Instruction::decode(self.memory, pc) {
+            match Instruction::decode(self.memory, pc, 3) {
                 Ok(instruction) => {
                     let disasm_line = self.format_instruction(pc, &instruction);
                     writeln!(&mut output, "{}", disasm_line).unwrap();
-                    
-                    pc += instruction.length;
+
+                    pc += instruction.size;
                     instructions_decoded += 1;
                 }
                 Err(e) => {
@@ -129,56 +148,64 @@ impl<'a> Disassembler<'a> {
             }
         }
 
-        writeln!(&mut output, "\nDisassembled {} instructions ({} bytes)", 
-                 instructions_decoded, pc - start_pc).unwrap();
-        
+        writeln!(
+            &mut output,
+            "\nDisassembled {} instructions ({} bytes)",
+            instructions_decoded,
+            pc - start_pc
+        )
+        .unwrap();
+
         Ok(output)
     }
 
     /// Format a single instruction for display
     fn format_instruction(&self, pc: usize, instruction: &Instruction) -> String {
         let mut output = String::new();
-        
+
         // Address and opcode
         write!(&mut output, "{:#06x}: ", pc).unwrap();
-        
+
         // Opcode name and form
         let opcode_name = self.get_opcode_name(instruction);
         write!(&mut output, "{:<15} ", opcode_name).unwrap();
-        
+
         // Operands
         if !instruction.operands.is_empty() {
-            let operands_str = instruction.operands.iter()
-                .map(|op| self.format_operand(op))
+            let operands_str = instruction
+                .operand_types
+                .iter()
+                .zip(instruction.operands.iter())
+                .map(|(op_type, &value)| self.format_operand(*op_type, value))
                 .collect::<Vec<_>>()
                 .join(", ");
             write!(&mut output, "{:<20} ", operands_str).unwrap();
         } else {
             write!(&mut output, "{:<20} ", "").unwrap();
         }
-        
+
         // Store variable
-        if let Some(store_var) = instruction.store_variable {
+        if let Some(store_var) = instruction.store_var {
             write!(&mut output, "-> {:<6} ", self.format_variable(store_var)).unwrap();
         } else {
             write!(&mut output, "{:<10} ", "").unwrap();
         }
-        
+
         // Branch offset
-        if let Some(offset) = instruction.branch_offset {
+        if let Some(offset) = instruction.branch.as_ref().map(|b| b.offset) {
             let branch_str = self.format_branch(pc, instruction, offset);
             write!(&mut output, "{}", branch_str).unwrap();
         }
-        
+
         output
     }
 
     /// Format an operand for display
-    fn format_operand(&self, operand: &crate::instruction::Operand) -> String {
-        match operand.operand_type {
-            OperandType::LargeConstant => format!("#{:#06x}", operand.value),
-            OperandType::SmallConstant => format!("#{:#04x}", operand.value),
-            OperandType::Variable => self.format_variable(operand.value as u8),
+    fn format_operand(&self, operand_type: OperandType, value: u16) -> String {
+        match operand_type {
+            OperandType::LargeConstant => format!("#{:#06x}", value),
+            OperandType::SmallConstant => format!("#{:#04x}", value),
+            OperandType::Variable => self.format_variable(value as u8),
             OperandType::Omitted => String::new(),
         }
     }
@@ -202,13 +229,14 @@ impl<'a> Disassembler<'a> {
         } else {
             ("TRUE", stored_offset)
         };
-        
+
         if actual_offset == 0 {
             format!("[{}: RFALSE]", condition)
         } else if actual_offset == 1 {
             format!("[{}: RTRUE]", condition)
         } else {
-            let target = (pc as i32 + instruction.length as i32 + actual_offset as i32 - 2) as usize;
+            let target =
+                (pc as i32 + instruction.size as i32 + actual_offset as i32 - 2) as usize;
             format!("[{}: {:#06x}]", condition, target)
         }
     }
@@ -217,109 +245,389 @@ impl<'a> Disassembler<'a> {
     fn get_opcode_name(&self, instruction: &Instruction) -> String {
         match (&instruction.operand_count, instruction.opcode) {
             // 0OP instructions
-            (OperandCount::Op0, 0x00) => "RTRUE",
-            (OperandCount::Op0, 0x01) => "RFALSE",
-            (OperandCount::Op0, 0x02) => "PRINT",
-            (OperandCount::Op0, 0x03) => "PRINT_RET",
-            (OperandCount::Op0, 0x04) => "NOP",
-            (OperandCount::Op0, 0x05) => "SAVE",
-            (OperandCount::Op0, 0x06) => "RESTORE",
-            (OperandCount::Op0, 0x07) => "RESTART",
-            (OperandCount::Op0, 0x08) => "RET_POPPED",
-            (OperandCount::Op0, 0x09) => "CATCH",
-            (OperandCount::Op0, 0x0a) => "QUIT",
-            (OperandCount::Op0, 0x0b) => "NEW_LINE",
-            (OperandCount::Op0, 0x0c) => "SHOW_STATUS",
-            (OperandCount::Op0, 0x0d) => "VERIFY",
-            (OperandCount::Op0, 0x0f) => "PIRACY",
+            (OperandCount::OP0, 0x00) => "RTRUE",
+            (OperandCount::OP0, 0x01) => "RFALSE",
+            (OperandCount::OP0, 0x02) => "PRINT",
+            (OperandCount::OP0, 0x03) => "PRINT_RET",
+            (OperandCount::OP0, 0x04) => "NOP",
+            (OperandCount::OP0, 0x05) => "SAVE",
+            (OperandCount::OP0, 0x06) => "RESTORE",
+            (OperandCount::OP0, 0x07) => "RESTART",
+            (OperandCount::OP0, 0x08) => "RET_POPPED",
+            (OperandCount::OP0, 0x09) => "CATCH",
+            (OperandCount::OP0, 0x0a) => "QUIT",
+            (OperandCount::OP0, 0x0b) => "NEW_LINE",
+            (OperandCount::OP0, 0x0c) => "SHOW_STATUS",
+            (OperandCount::OP0, 0x0d) => "VERIFY",
+            (OperandCount::OP0, 0x0f) => "PIRACY",
 
             // 1OP instructions
-            (OperandCount::Op1, 0x00) => "JZ",
-            (OperandCount::Op1, 0x01) => "GET_SIBLING",
-            (OperandCount::Op1, 0x02) => "GET_CHILD",
-            (OperandCount::Op1, 0x03) => "GET_PARENT",
-            (OperandCount::Op1, 0x04) => "GET_PROP_LEN",
-            (OperandCount::Op1, 0x05) => "INC",
-            (OperandCount::Op1, 0x06) => "DEC",
-            (OperandCount::Op1, 0x07) => "PRINT_ADDR",
-            (OperandCount::Op1, 0x08) => "CALL_1S",
-            (OperandCount::Op1, 0x09) => "REMOVE_OBJ",
-            (OperandCount::Op1, 0x0a) => "PRINT_OBJ",
-            (OperandCount::Op1, 0x0b) => "RET",
-            (OperandCount::Op1, 0x0c) => "JUMP",
-            (OperandCount::Op1, 0x0d) => "PRINT_PADDR",
-            (OperandCount::Op1, 0x0e) => "LOAD",
-            (OperandCount::Op1, 0x0f) => "NOT",
+            (OperandCount::OP1, 0x00) => "JZ",
+            (OperandCount::OP1, 0x01) => "GET_SIBLING",
+            (OperandCount::OP1, 0x02) => "GET_CHILD",
+            (OperandCount::OP1, 0x03) => "GET_PARENT",
+            (OperandCount::OP1, 0x04) => "GET_PROP_LEN",
+            (OperandCount::OP1, 0x05) => "INC",
+            (OperandCount::OP1, 0x06) => "DEC",
+            (OperandCount::OP1, 0x07) => "PRINT_ADDR",
+            (OperandCount::OP1, 0x08) => "CALL_1S",
+            (OperandCount::OP1, 0x09) => "REMOVE_OBJ",
+            (OperandCount::OP1, 0x0a) => "PRINT_OBJ",
+            (OperandCount::OP1, 0x0b) => "RET",
+            (OperandCount::OP1, 0x0c) => "JUMP",
+            (OperandCount::OP1, 0x0d) => "PRINT_PADDR",
+            (OperandCount::OP1, 0x0e) => "LOAD",
+            (OperandCount::OP1, 0x0f) => "NOT",
 
             // 2OP instructions
-            (OperandCount::Op2, 0x01) => "JE",
-            (OperandCount::Op2, 0x02) => "JL",
-            (OperandCount::Op2, 0x03) => "JG",
-            (OperandCount::Op2, 0x04) => "DEC_CHK",
-            (OperandCount::Op2, 0x05) => "INC_CHK",
-            (OperandCount::Op2, 0x06) => "JIN",
-            (OperandCount::Op2, 0x07) => "TEST",
-            (OperandCount::Op2, 0x08) => "OR",
-            (OperandCount::Op2, 0x09) => "AND",
-            (OperandCount::Op2, 0x0a) => "TEST_ATTR",
-            (OperandCount::Op2, 0x0b) => "SET_ATTR",
-            (OperandCount::Op2, 0x0c) => "CLEAR_ATTR",
-            (OperandCount::Op2, 0x0d) => "STORE",
-            (OperandCount::Op2, 0x0e) => "INSERT_OBJ",
-            (OperandCount::Op2, 0x0f) => "LOADW",
-            (OperandCount::Op2, 0x10) => "LOADB",
-            (OperandCount::Op2, 0x11) => "GET_PROP",
-            (OperandCount::Op2, 0x12) => "GET_PROP_ADDR",
-            (OperandCount::Op2, 0x13) => "GET_NEXT_PROP",
-            (OperandCount::Op2, 0x14) => "ADD",
-            (OperandCount::Op2, 0x15) => "SUB",
-            (OperandCount::Op2, 0x16) => "MUL",
-            (OperandCount::Op2, 0x17) => "DIV",
-            (OperandCount::Op2, 0x18) => "MOD",
-            (OperandCount::Op2, 0x19) => "CALL_2S",
+            (OperandCount::OP2, 0x01) => "JE",
+            (OperandCount::OP2, 0x02) => "JL",
+            (OperandCount::OP2, 0x03) => "JG",
+            (OperandCount::OP2, 0x04) => "DEC_CHK",
+            (OperandCount::OP2, 0x05) => "INC_CHK",
+            (OperandCount::OP2, 0x06) => "JIN",
+            (OperandCount::OP2, 0x07) => "TEST",
+            (OperandCount::OP2, 0x08) => "OR",
+            (OperandCount::OP2, 0x09) => "AND",
+            (OperandCount::OP2, 0x0a) => "TEST_ATTR",
+            (OperandCount::OP2, 0x0b) => "SET_ATTR",
+            (OperandCount::OP2, 0x0c) => "CLEAR_ATTR",
+            (OperandCount::OP2, 0x0d) => "STORE",
+            (OperandCount::OP2, 0x0e) => "INSERT_OBJ",
+            (OperandCount::OP2, 0x0f) => "LOADW",
+            (OperandCount::OP2, 0x10) => "LOADB",
+            (OperandCount::OP2, 0x11) => "GET_PROP",
+            (OperandCount::OP2, 0x12) => "GET_PROP_ADDR",
+            (OperandCount::OP2, 0x13) => "GET_NEXT_PROP",
+            (OperandCount::OP2, 0x14) => "ADD",
+            (OperandCount::OP2, 0x15) => "SUB",
+            (OperandCount::OP2, 0x16) => "MUL",
+            (OperandCount::OP2, 0x17) => "DIV",
+            (OperandCount::OP2, 0x18) => "MOD",
+            (OperandCount::OP2, 0x19) => "CALL_2S",
 
             // VAR instructions
-            (OperandCount::Var, 0x00) => "CALL",
-            (OperandCount::Var, 0x01) => "STOREW",
-            (OperandCount::Var, 0x02) => "STOREB",
-            (OperandCount::Var, 0x03) => "PUT_PROP",
-            (OperandCount::Var, 0x04) => "SREAD",
-            (OperandCount::Var, 0x05) => "PRINT_CHAR",
-            (OperandCount::Var, 0x06) => "PRINT_NUM",
-            (OperandCount::Var, 0x07) => "RANDOM",
-            (OperandCount::Var, 0x08) => "PUSH",
-            (OperandCount::Var, 0x09) => "PULL",
-            (OperandCount::Var, 0x0a) => "SPLIT_WINDOW",
-            (OperandCount::Var, 0x0b) => "SET_WINDOW",
-            (OperandCount::Var, 0x0c) => "CALL_VS2",
-            (OperandCount::Var, 0x0d) => "ERASE_WINDOW",
-            (OperandCount::Var, 0x0e) => "ERASE_LINE",
-            (OperandCount::Var, 0x0f) => "SET_CURSOR",
-            (OperandCount::Var, 0x10) => "GET_CURSOR",
-            (OperandCount::Var, 0x11) => "SET_TEXT_STYLE",
-            (OperandCount::Var, 0x12) => "BUFFER_MODE",
-            (OperandCount::Var, 0x13) => "OUTPUT_STREAM",
-            (OperandCount::Var, 0x14) => "INPUT_STREAM",
-            (OperandCount::Var, 0x15) => "SOUND_EFFECT",
-            (OperandCount::Var, 0x16) => "READ_CHAR",
-            (OperandCount::Var, 0x17) => "SCAN_TABLE",
-            (OperandCount::Var, 0x18) => "NOT",
-            (OperandCount::Var, 0x19) => "CALL_VN",
-            (OperandCount::Var, 0x1a) => "CALL_VN2",
-            (OperandCount::Var, 0x1b) => "TOKENISE",
-            (OperandCount::Var, 0x1c) => "ENCODE_TEXT",
-            (OperandCount::Var, 0x1d) => "COPY_TABLE",
-            (OperandCount::Var, 0x1e) => "PRINT_TABLE",
-            (OperandCount::Var, 0x1f) => "CHECK_ARG_COUNT",
+            (OperandCount::VAR, 0x00) => "CALL",
+            (OperandCount::VAR, 0x01) => "STOREW",
+            (OperandCount::VAR, 0x02) => "STOREB",
+            (OperandCount::VAR, 0x03) => "PUT_PROP",
+            (OperandCount::VAR, 0x04) => "SREAD",
+            (OperandCount::VAR, 0x05) => "PRINT_CHAR",
+            (OperandCount::VAR, 0x06) => "PRINT_NUM",
+            (OperandCount::VAR, 0x07) => "RANDOM",
+            (OperandCount::VAR, 0x08) => "PUSH",
+            (OperandCount::VAR, 0x09) => "PULL",
+            (OperandCount::VAR, 0x0a) => "SPLIT_WINDOW",
+            (OperandCount::VAR, 0x0b) => "SET_WINDOW",
+            (OperandCount::VAR, 0x0c) => "CALL_VS2",
+            (OperandCount::VAR, 0x0d) => "ERASE_WINDOW",
+            (OperandCount::VAR, 0x0e) => "ERASE_LINE",
+            (OperandCount::VAR, 0x0f) => "SET_CURSOR",
+            (OperandCount::VAR, 0x10) => "GET_CURSOR",
+            (OperandCount::VAR, 0x11) => "SET_TEXT_STYLE",
+            (OperandCount::VAR, 0x12) => "BUFFER_MODE",
+            (OperandCount::VAR, 0x13) => "OUTPUT_STREAM",
+            (OperandCount::VAR, 0x14) => "INPUT_STREAM",
+            (OperandCount::VAR, 0x15) => "SOUND_EFFECT",
+            (OperandCount::VAR, 0x16) => "READ_CHAR",
+            (OperandCount::VAR, 0x17) => "SCAN_TABLE",
+            (OperandCount::VAR, 0x18) => "NOT",
+            (OperandCount::VAR, 0x19) => "CALL_VN",
+            (OperandCount::VAR, 0x1a) => "CALL_VN2",
+            (OperandCount::VAR, 0x1b) => "TOKENISE",
+            (OperandCount::VAR, 0x1c) => "ENCODE_TEXT",
+            (OperandCount::VAR, 0x1d) => "COPY_TABLE",
+            (OperandCount::VAR, 0x1e) => "PRINT_TABLE",
+            (OperandCount::VAR, 0x1f) => "CHECK_ARG_COUNT",
 
             _ => {
                 if instruction.form == InstructionForm::Extended {
                     return format!("EXT_{:#04x}", instruction.opcode);
                 } else {
-                    return format!("UNK_{:?}_{:#04x}", instruction.operand_count, instruction.opcode);
+                    return format!(
+                        "UNK_{:?}_{:#04x}",
+                        instruction.operand_count, instruction.opcode
+                    );
+                }
+            }
+        }
+        .to_string()
+    }
+
+    /// Disassemble every routine reachable from `header.initial_pc` plus
+    /// `extra_entry_points` (e.g. dictionary verb routines and object action
+    /// routines, which the caller resolves since this module has no dictionary or
+    /// object-table knowledge of its own).
+    ///
+    /// Unlike [`Disassembler::disassemble`]'s fixed linear sweep, this follows control
+    /// flow: every `call*` operand is unpacked into a routine address and queued, every
+    /// branch/jump target is computed and queued, and a trace stops once it hits
+    /// `ret`/`rtrue`/`rfalse`/`ret_popped`/`quit` or an unconditional `jump`. Routine
+    /// entries are validated against the locals-count byte that must precede their code
+    /// (0-15 locals); `extra_entry_points` are always treated as routine addresses, while
+    /// `header.initial_pc` is a raw code address in V1-5 and a packed routine address in
+    /// V6/7, per the spec's special case for the game's start address.
+    ///
+    /// Every discovered target is annotated with where it was reached from. A target
+    /// that lands below `header.base_static_mem` is flagged as a suspected corrupt call
+    /// rather than walked into, since legitimate routines live in static/high memory and
+    /// landing in dynamic memory is the signature of a mis-decoded operand.
+    pub fn disassemble_program(
+        &self,
+        header: &Header,
+        extra_entry_points: &[u32],
+        symbols: &SymbolTable,
+    ) -> Result<String, String> {
+        let version = header.version;
+
+        let mut worklist: VecDeque<(u32, bool)> = VecDeque::new();
+        let mut queued: HashSet<u32> = HashSet::new();
+        let mut visited: HashSet<u32> = HashSet::new();
+        let mut xrefs: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut corrupt: Vec<(u32, u32)> = Vec::new();
+
+        let initial_is_packed = matches!(version, 6 | 7);
+        let initial_entry = if initial_is_packed {
+            unpack_routine_address(header.initial_pc as u16, version, header)
+        } else {
+            header.initial_pc as u32
+        };
+        worklist.push_back((initial_entry, initial_is_packed));
+        queued.insert(initial_entry);
+        for &addr in extra_entry_points {
+            if queued.insert(addr) {
+                worklist.push_back((addr, true));
+            }
+        }
+
+        let mut blocks: Vec<(u32, bool, Vec<(u32, Instruction)>, Option<String>)> = Vec::new();
+
+        while let Some((addr, is_routine)) = worklist.pop_front() {
+            if visited.contains(&addr) {
+                continue;
+            }
+
+            let mut pc = addr;
+            if is_routine {
+                if pc as usize >= self.memory.len() {
+                    corrupt.push((addr, addr));
+                    continue;
+                }
+                let num_locals = self.memory[pc as usize];
+                if num_locals > 15 {
+                    corrupt.push((addr, addr));
+                    continue;
+                }
+                let header_size = if version <= 4 {
+                    1 + num_locals as u32 * 2
+                } else {
+                    1
+                };
+                pc += header_size;
+            }
+
+            let mut block = Vec::new();
+            let mut decode_error = None;
+            loop {
+                if visited.contains(&pc) {
+                    break;
+                }
+                visited.insert(pc);
+
+                match Instruction::decode(self.memory, pc as usize, version) {
+                    Ok(inst) => {
+                        let name = get_instruction_name(
+                            inst.opcode,
+                            inst.ext_opcode,
+                            inst.form,
+                            inst.operand_count,
+                            version,
+                        );
+                        let next_pc = pc + inst.size as u32;
+
+                        if let Some(ref branch) = inst.branch {
+                            if branch.offset != 0 && branch.offset != 1 {
+                                let target = next_pc as i64 + branch.offset as i64 - 2;
+                                self.queue_target(
+                                    target,
+                                    pc,
+                                    false,
+                                    header,
+                                    &mut queued,
+                                    &mut xrefs,
+                                    &mut corrupt,
+                                    &mut worklist,
+                                );
+                            }
+                        }
+
+                        let is_call = name.starts_with("call");
+                        if is_call {
+                            if let Some(&packed) = inst.operands.first() {
+                                if packed != 0 {
+                                    let target =
+                                        unpack_routine_address(packed, version, header) as i64;
+                                    self.queue_target(
+                                        target,
+                                        pc,
+                                        true,
+                                        header,
+                                        &mut queued,
+                                        &mut xrefs,
+                                        &mut corrupt,
+                                        &mut worklist,
+                                    );
+                                }
+                            }
+                        }
+
+                        let is_jump = name == "jump";
+                        if is_jump {
+                            if let Some(&offset) = inst.operands.first() {
+                                let target = next_pc as i64 + (offset as i16) as i64 - 2;
+                                self.queue_target(
+                                    target,
+                                    pc,
+                                    false,
+                                    header,
+                                    &mut queued,
+                                    &mut xrefs,
+                                    &mut corrupt,
+                                    &mut worklist,
+                                );
+                            }
+                        }
+
+                        let terminates = is_jump
+                            || matches!(name, "rtrue" | "rfalse" | "ret" | "ret_popped" | "quit");
+                        block.push((pc, inst));
+                        pc = next_pc;
+                        if terminates {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        decode_error = Some(format!("{:#06x}: <decode error: {}>", pc, e));
+                        break;
+                    }
                 }
             }
-        }.to_string()
+
+            blocks.push((addr, is_routine, block, decode_error));
+        }
+
+        blocks.sort_by_key(|(addr, ..)| *addr);
+
+        let mut output = String::new();
+        writeln!(
+            &mut output,
+            "Reachability disassembly from {:#06x} ({} routines/labels reached):",
+            initial_entry,
+            blocks.len()
+        )
+        .unwrap();
+        writeln!(&mut output).unwrap();
+
+        for (addr, is_routine, block, decode_error) in &blocks {
+            let callers = xrefs.get(addr);
+            let xref_comment = callers
+                .map(|from| {
+                    let list = from
+                        .iter()
+                        .map(|a| format!("{:#06x}", a))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(" ; called from {list}")
+                })
+                .unwrap_or_default();
+
+            if *is_routine {
+                writeln!(&mut output, "R_{:04x}:{xref_comment}", addr).unwrap();
+                let num_locals = self.memory[*addr as usize];
+                writeln!(
+                    &mut output,
+                    "{:#06x}: === ROUTINE START ({} locals) ===",
+                    addr, num_locals
+                )
+                .unwrap();
+            } else {
+                writeln!(&mut output, "L_{:04x}:{xref_comment}", addr).unwrap();
+            }
+
+            for (pc, inst) in block {
+                writeln!(
+                    &mut output,
+                    "{:#06x}: {}",
+                    pc,
+                    format_instruction_with_symbols(*pc, inst, version, header, symbols)
+                )
+                .unwrap();
+            }
+            if let Some(err) = decode_error {
+                writeln!(&mut output, "{err}").unwrap();
+            }
+            writeln!(&mut output).unwrap();
+        }
+
+        if !corrupt.is_empty() {
+            writeln!(
+                &mut output,
+                "Suspected corrupt calls/branches (target below base_static_mem {:#06x}, past end of memory, or with a locals count over 15):",
+                header.base_static_mem
+            )
+            .unwrap();
+            for (target, from) in &corrupt {
+                writeln!(
+                    &mut output,
+                    "  {:#06x} <- referenced from {:#06x}",
+                    target, from
+                )
+                .unwrap();
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Resolve a computed branch/call/jump target: out-of-range or below
+    /// `header.base_static_mem` targets are recorded as suspected corrupt rather than
+    /// queued for decoding; otherwise the target is cross-referenced and queued if new.
+    fn queue_target(
+        &self,
+        target: i64,
+        from: u32,
+        is_routine: bool,
+        header: &Header,
+        queued: &mut HashSet<u32>,
+        xrefs: &mut HashMap<u32, Vec<u32>>,
+        corrupt: &mut Vec<(u32, u32)>,
+        worklist: &mut VecDeque<(u32, bool)>,
+    ) {
+        if target < 0 || target as usize >= self.memory.len() {
+            corrupt.push((target.max(0) as u32, from));
+            return;
+        }
+        let target = target as u32;
+        if (target as usize) < header.base_static_mem {
+            corrupt.push((target, from));
+            return;
+        }
+
+        xrefs.entry(target).or_default().push(from);
+        if queued.insert(target) {
+            worklist.push_back((target, is_routine));
+        }
+    }
+}
+
+/// Unpack a packed routine address per the Z-Machine spec: `2*packed` in V1-3,
+/// `4*packed` in V4-5, `4*packed + 8*routine_offset` in V6-7, and `8*packed` in V8.
+pub(crate) fn unpack_routine_address(packed: u16, version: u8, header: &Header) -> u32 {
+    match version {
+        1..=3 => packed as u32 * 2,
+        4 | 5 => packed as u32 * 4,
+        6 | 7 => packed as u32 * 4 + header.routine_offset as u32 * 8,
+        _ => packed as u32 * 8,
     }
 }
 
@@ -331,7 +639,11 @@ pub fn disassemble_range(memory: &[u8], start_pc: usize, end_pc: usize) -> Resul
 }
 
 /// Convenience function to disassemble a specific number of instructions
-pub fn disassemble_instructions(memory: &[u8], start_pc: usize, count: usize) -> Result<String, String> {
+pub fn disassemble_instructions(
+    memory: &[u8],
+    start_pc: usize,
+    count: usize,
+) -> Result<String, String> {
     let disassembler = Disassembler::new(memory);
     disassembler.disassemble(start_pc, Some(count), None)
 }
@@ -339,7 +651,7 @@ pub fn disassemble_instructions(memory: &[u8], start_pc: usize, count: usize) ->
 /// Disassemble a routine at a given packed address
 pub fn disassemble_routine(memory: &[u8], packed_addr: u16, version: u8) -> Result<String, String> {
     let disassembler = Disassembler::new(memory);
-    
+
     // Convert packed address to byte address
     let byte_addr = match version {
         1 | 2 | 3 => (packed_addr as usize) * 2,
@@ -351,28 +663,37 @@ pub fn disassemble_routine(memory: &[u8], packed_addr: u16, version: u8) -> Resu
         }
         _ => (packed_addr as usize) * 2,
     };
-    
+
     let mut output = String::new();
-    writeln!(&mut output, "Routine at packed address {:#06x} (byte address {:#06x}):", 
-             packed_addr, byte_addr).unwrap();
+    writeln!(
+        &mut output,
+        "Routine at packed address {:#06x} (byte address {:#06x}):",
+        packed_addr, byte_addr
+    )
+    .unwrap();
     writeln!(&mut output, "").unwrap();
-    
+
     // First, format the routine header
     if byte_addr < memory.len() {
         output.push_str(&disassembler.format_routine_header(byte_addr));
-        
+
         // Then disassemble the code
         let num_locals = memory[byte_addr];
         let code_start = byte_addr + 1 + (num_locals as usize * 2);
-        
+
         // Disassemble up to 100 instructions or until we hit what looks like another routine
         match disassembler.disassemble(code_start, Some(100), None) {
             Ok(disasm) => output.push_str(&disasm),
             Err(e) => writeln!(&mut output, "Error disassembling routine: {}", e).unwrap(),
         }
     } else {
-        writeln!(&mut output, "Error: Address {:#06x} is out of bounds", byte_addr).unwrap();
+        writeln!(
+            &mut output,
+            "Error: Address {:#06x} is out of bounds",
+            byte_addr
+        )
+        .unwrap();
     }
-    
+
     Ok(output)
-}
\ No newline at end of file
+}
@@ -1,7 +1,121 @@
 #[cfg(test)]
 mod tests {
     use crate::disassembler::{Disassembler, disassemble_instructions, disassemble_range};
-    
+    use crate::header::Header;
+    use crate::symbols::SymbolTable;
+
+    /// Build a minimal V1-5 header with just the fields `disassemble_program` reads:
+    /// version, start PC, and the dynamic/static memory boundary.
+    fn build_header(version: u8, initial_pc: u16, base_static_mem: u16) -> Header {
+        let mut bytes = vec![0u8; 64];
+        bytes[0] = version;
+        bytes[6] = (initial_pc >> 8) as u8;
+        bytes[7] = (initial_pc & 0xFF) as u8;
+        bytes[14] = (base_static_mem >> 8) as u8;
+        bytes[15] = (base_static_mem & 0xFF) as u8;
+        Header::new(&bytes)
+    }
+
+    #[test]
+    fn test_disassemble_program_linear_routine() {
+        // Entry point (V3, so not itself a routine-header block) is a straight run of
+        // instructions with no calls or branches, ending in RTRUE.
+        let base = 0x40usize;
+        let mut memory = vec![0u8; base];
+        memory.extend_from_slice(&[0xb4, 0xb0]); // NOP (falls through), RTRUE (terminates)
+
+        let header = build_header(3, base as u16, base as u16);
+        let disasm = Disassembler::new(&memory);
+        let result = disasm.disassemble_program(&header, &[], &SymbolTable::new()).unwrap();
+
+        assert!(result.contains("L_0040"));
+        assert!(result.contains("nop"));
+        assert!(result.contains("rtrue"));
+        assert!(!result.contains("Suspected corrupt"));
+    }
+
+    #[test]
+    fn test_disassemble_program_forward_call_and_branch() {
+        // Entry calls a routine ahead of it (forward call) and also branches forward
+        // over its own fallthrough instruction (forward branch) before terminating.
+        let base = 0x40usize;
+        let mut memory = vec![0u8; base];
+        memory.extend_from_slice(&[
+            0xE0, 0x7F, 0x25, 0x00, // call (VAR:224) routine #0x25 (-> addr 0x4a), store -> sp
+            0xa0, 0x00, 0xC3, // jz sp ?(+3) -> branches to entry+8
+            0xb0, // rtrue (fallthrough terminator, entry+7)
+            0xb1, // rfalse (branch target, entry+8)
+            0x00, // padding (dead space, entry+9)
+            0x00, // routine header: 0 locals (entry+10 = 0x4a, matches packed addr 0x25 * 2)
+            0xb0, // rtrue (entry+11)
+        ]);
+
+        let header = build_header(3, base as u16, base as u16);
+        let disasm = Disassembler::new(&memory);
+        let result = disasm.disassemble_program(&header, &[], &SymbolTable::new()).unwrap();
+
+        assert!(result.contains("L_0040"), "{result}");
+        assert!(result.contains("R_004a"), "{result}");
+        assert!(result.contains("=== ROUTINE START (0 locals) ==="), "{result}");
+        assert!(result.contains("L_0048"), "{result}"); // forward branch target
+        assert!(result.contains("called from"), "{result}"); // xref annotation
+        assert!(result.contains("call "), "{result}");
+        assert!(result.contains("jz "), "{result}");
+        assert!(!result.contains("Suspected corrupt"), "{result}");
+    }
+
+    #[test]
+    fn test_disassemble_program_backward_branch_loop() {
+        // Entry calls a routine that branches backward to its own first instruction
+        // (a loop). The backward target is already visited by the time it's queued, so
+        // it must not be re-disassembled as a second, duplicate block.
+        let base = 0x40usize;
+        let mut memory = vec![0u8; base];
+        memory.extend_from_slice(&[
+            0xE0, 0x7F, 0x28, 0x00, // call routine #0x28 (-> addr 0x50), store -> sp
+            0xba, // quit (entry+4, terminates entry block)
+        ]);
+        // Routine at 0x50 (packed #0x28 * 2): 0 locals, then a self-looping jz, then rtrue.
+        memory.resize(0x50, 0);
+        memory.extend_from_slice(&[
+            0x00, // 0 locals (routine header at 0x50)
+            0xa0, 0x00, 0xBF, 0xFE, // jz sp ?(-2) -> branches back to 0x51 (itself)
+            0xb0, // rtrue (fallthrough terminator)
+        ]);
+
+        let header = build_header(3, base as u16, base as u16);
+        let disasm = Disassembler::new(&memory);
+        let result = disasm.disassemble_program(&header, &[], &SymbolTable::new()).unwrap();
+
+        assert!(result.contains("R_0050"), "{result}");
+        assert!(result.contains("jz "), "{result}");
+        assert!(result.contains("rtrue"), "{result}");
+        // The backward target (0x51, the jz instruction itself) must not produce its
+        // own separate label block - it's already covered by the routine's block.
+        assert!(!result.contains("L_0051"), "{result}");
+        assert!(!result.contains("Suspected corrupt"), "{result}");
+    }
+
+    #[test]
+    fn test_disassemble_program_flags_corrupt_call_target() {
+        // Entry calls a packed address that unpacks to well below base_static_mem -
+        // the signature of a mis-decoded operand - which must be flagged, not walked.
+        let base = 0x40usize;
+        let mut memory = vec![0u8; base];
+        memory.extend_from_slice(&[
+            0xE0, 0x7F, 0x01, 0x00, // call routine #0x01 (-> addr 0x02, in header space)
+            0xba, // quit
+        ]);
+
+        let header = build_header(3, base as u16, base as u16);
+        let disasm = Disassembler::new(&memory);
+        let result = disasm.disassemble_program(&header, &[], &SymbolTable::new()).unwrap();
+
+        assert!(result.contains("Suspected corrupt"), "{result}");
+        assert!(result.contains("0x0002"), "{result}");
+        assert!(!result.contains("R_0002"), "{result}"); // never walked into
+    }
+
     #[test]
     fn test_basic_disassembly() {
         // Simple test with a few known instructions
@@ -2,7 +2,7 @@
 //!
 //! This wrapper logs every single display operation to help debug display issues.
 
-use crate::display_trait::{DisplayError, ZMachineDisplay};
+use crate::display_trait::{DisplayError, StatusLineMode, ZMachineDisplay};
 use log::{debug, info};
 
 pub struct LoggingDisplay {
@@ -69,12 +69,18 @@ impl ZMachineDisplay for LoggingDisplay {
         self.inner.handle_resize(width, height)
     }
 
-    fn show_status(&mut self, location: &str, score: i16, moves: u16) -> Result<(), DisplayError> {
+    fn show_status(
+        &mut self,
+        location: &str,
+        mode: StatusLineMode,
+        a: i16,
+        b: u16,
+    ) -> Result<(), DisplayError> {
         self.log_op(&format!(
-            "show_status('{}', {}, {})",
-            location, score, moves
+            "show_status('{}', {:?}, {}, {})",
+            location, mode, a, b
         ));
-        self.inner.show_status(location, score, moves)
+        self.inner.show_status(location, mode, a, b)
     }
 
     fn set_text_style(&mut self, style: u16) -> Result<(), DisplayError> {
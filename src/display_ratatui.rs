@@ -31,15 +31,17 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Paragraph, Wrap},
     Terminal,
 };
+use std::collections::VecDeque;
 use std::io::{self, Stdout};
 use std::process::Command;
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
 use std::time::Duration;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Commands sent to the display thread
 #[derive(Debug)]
@@ -50,13 +52,94 @@ pub enum DisplayCommand {
     Print(String),
     PrintChar(char),
     EraseWindow(i16),
-    ShowStatus(String, i16, u16),
+    ShowStatus(String, StatusLineMode, i16, u16),
     SetTextStyle(u16),
+    /// Z-Machine `set_colour` (foreground, background); each is a standard Z-Machine
+    /// colour number (1 = current, 2 = default, 3-13 = the named palette)
+    SetColour(u8, u8),
     ClearScreen,
     EraseLine, // v4+
+    /// Scroll the lower-window viewport up/down by one display row
+    ScrollLineUp,
+    ScrollLineDown,
+    /// Scroll the lower-window viewport up/down by one page (the viewport height)
+    ScrollPageUp,
+    ScrollPageDown,
+    /// Snap the lower-window viewport back to the bottom (latest output)
+    ScrollToBottom,
+    /// An interactive `sread`/`read_char` loop is starting (`true`) or finishing
+    /// (`false`); see `DisplayState::input_active`.
+    SetInputActive(bool),
     Quit,
 }
 
+/// Number of completed lower-window lines retained for scrollback (see `DisplayState::lower_window_content`)
+const SCROLLBACK_LINES: usize = 2000;
+
+/// Per-cell text attributes, analogous to Alacritty's `Flags` bitset: reverse video,
+/// bold, italic, and fixed-pitch are independent and can combine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct CellAttrs {
+    reverse: bool,
+    bold: bool,
+    italic: bool,
+    fixed_pitch: bool,
+}
+
+impl CellAttrs {
+    /// Derive attributes from a Z-Machine `set_text_style` bitmask
+    /// (1 = reverse, 2 = bold, 4 = italic, 8 = fixed-pitch).
+    fn from_style_bits(style_bits: u16) -> Self {
+        CellAttrs {
+            reverse: style_bits & 1 != 0,
+            bold: style_bits & 2 != 0,
+            italic: style_bits & 4 != 0,
+            fixed_pitch: style_bits & 8 != 0,
+        }
+    }
+
+    /// Convert to a ratatui `Style`'s modifier set (colors are applied separately).
+    fn to_modifier(self) -> Modifier {
+        let mut modifier = Modifier::empty();
+        if self.reverse {
+            modifier |= Modifier::REVERSED;
+        }
+        if self.bold {
+            modifier |= Modifier::BOLD;
+        }
+        if self.italic {
+            modifier |= Modifier::ITALIC;
+        }
+        modifier
+    }
+}
+
+/// A Z-Machine standard colour number, as used by `set_colour` (1 = current, 2 = default,
+/// 3-13 = the named palette). Stored raw so rendering can fall back sanely on unknown codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ZColor(u8);
+
+impl ZColor {
+    /// Map a standard Z-Machine colour number to a ratatui `Color`. Colours 1 (current)
+    /// and 2 (default) resolve to `None`, meaning "leave the terminal's default".
+    fn to_ratatui(self) -> Option<Color> {
+        match self.0 {
+            3 => Some(Color::Black),
+            4 => Some(Color::Red),
+            5 => Some(Color::Green),
+            6 => Some(Color::Yellow),
+            7 => Some(Color::Blue),
+            8 => Some(Color::Magenta),
+            9 => Some(Color::Cyan),
+            10 => Some(Color::White),
+            11 => Some(Color::Gray),
+            12 => Some(Color::DarkGray),
+            13 => Some(Color::Black),
+            _ => None, // 1 = current, 2 = default, or an unrecognized code
+        }
+    }
+}
+
 /// Display manager using Ratatui
 pub struct RatatuiDisplay {
     /// Channel to send commands to display thread
@@ -77,27 +160,62 @@ struct DisplayState {
     current_window: u8,
     /// Upper window content with style information
     upper_window_content: Vec<Vec<StyledChar>>,
-    /// Lower window content as scrolling text lines
-    lower_window_content: Vec<String>,
+    /// Lower window scrollback: a bounded ring buffer of completed lines, each a run of
+    /// styled characters, retaining up to `SCROLLBACK_LINES` so PgUp/PgDn can review text
+    /// that has scrolled past.
+    lower_window_content: VecDeque<Vec<StyledChar>>,
     /// Cursor position in upper window
     upper_cursor_x: u16,
     upper_cursor_y: u16,
     /// Current line being built in lower window
-    lower_current_line: String,
-    /// Current text style
-    text_style: Style,
+    lower_current_line: Vec<StyledChar>,
+    /// Rows scrolled back from the bottom of the lower window (0 = pinned to latest output)
+    scroll_position: usize,
+    /// Attributes applied to newly printed characters (from `set_text_style`)
+    current_attrs: CellAttrs,
+    /// Foreground/background colours applied to newly printed characters (from `set_colour`)
+    current_fg: Option<ZColor>,
+    current_bg: Option<ZColor>,
     /// Terminal dimensions
     terminal_width: u16,
     terminal_height: u16,
-    /// Track if reverse video is currently active
-    reverse_video_active: bool,
+    /// Set while an interactive `sread`/`read_char` loop owns the terminal event stream
+    /// (see `DisplayCommand::SetInputActive`). While true, this thread's own poll loop
+    /// below stays off crossterm entirely so it can't steal keystrokes meant for input.
+    input_active: bool,
 }
 
-/// A character with associated styling
+/// A character with its full per-cell attribute set and optional colours, stamped at
+/// print time from `DisplayState`'s current attributes/colours.
 #[derive(Clone, Debug)]
 struct StyledChar {
     ch: char,
-    reverse_video: bool,
+    attrs: CellAttrs,
+    fg: Option<ZColor>,
+    bg: Option<ZColor>,
+}
+
+impl StyledChar {
+    fn plain(ch: char) -> Self {
+        StyledChar {
+            ch,
+            attrs: CellAttrs::default(),
+            fg: None,
+            bg: None,
+        }
+    }
+
+    /// Render this cell's attributes/colours as a ratatui `Style`.
+    fn to_style(&self) -> Style {
+        let mut style = Style::default().add_modifier(self.attrs.to_modifier());
+        if let Some(fg) = self.fg.and_then(ZColor::to_ratatui) {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = self.bg.and_then(ZColor::to_ratatui) {
+            style = style.bg(bg);
+        }
+        style
+    }
 }
 
 /// Get terminal size from environment variables or stty as fallback
@@ -202,23 +320,26 @@ impl RatatuiDisplay {
     }
 
     /// Show status line
-    pub fn show_status(&mut self, location: &str, score: i16, moves: u16) -> Result<(), String> {
-        self.show_status_with_version(location, score, moves, 3)
+    pub fn show_status(
+        &mut self,
+        location: &str,
+        mode: StatusLineMode,
+        a: i16,
+        b: u16,
+    ) -> Result<(), String> {
+        self.show_status_with_version(location, mode, a, b, 3)
     }
 
     /// Show status line with version-specific behavior
     pub fn show_status_with_version(
         &mut self,
         location: &str,
-        score: i16,
-        moves: u16,
+        mode: StatusLineMode,
+        a: i16,
+        b: u16,
         _version: u8,
     ) -> Result<(), String> {
-        self.send_command(DisplayCommand::ShowStatus(
-            location.to_string(),
-            score,
-            moves,
-        ))
+        self.send_command(DisplayCommand::ShowStatus(location.to_string(), mode, a, b))
     }
 
     /// Set text style
@@ -226,6 +347,14 @@ impl RatatuiDisplay {
         self.send_command(DisplayCommand::SetTextStyle(style))
     }
 
+    /// Set current foreground/background colours (Z-Machine `set_colour`)
+    pub fn set_colour(&mut self, foreground: u16, background: u16) -> Result<(), String> {
+        self.send_command(DisplayCommand::SetColour(
+            foreground as u8,
+            background as u8,
+        ))
+    }
+
     /// Handle terminal resize
     pub fn handle_resize(&mut self, _new_width: u16, _new_height: u16) {
         // Ratatui handles resize automatically
@@ -295,9 +424,16 @@ impl RatatuiDisplay {
         // Ratatui handles refresh automatically
         Ok(())
     }
+
+    /// Tell the display thread whether an interactive `sread`/`read_char` loop currently
+    /// owns the terminal event stream, so its own poll loop can stay off crossterm while
+    /// that's true (see `DisplayCommand::SetInputActive`).
+    pub fn set_input_active(&mut self, active: bool) -> Result<(), String> {
+        self.send_command(DisplayCommand::SetInputActive(active))
+    }
 }
 
-use crate::display_trait::{DisplayError, ZMachineDisplay};
+use crate::display_trait::{truncate_to_width, DisplayError, StatusLineMode, ZMachineDisplay};
 
 impl ZMachineDisplay for RatatuiDisplay {
     fn clear_screen(&mut self) -> Result<(), DisplayError> {
@@ -332,8 +468,14 @@ impl ZMachineDisplay for RatatuiDisplay {
         self.handle_resize(width, height);
     }
 
-    fn show_status(&mut self, location: &str, score: i16, moves: u16) -> Result<(), DisplayError> {
-        self.show_status(location, score, moves)
+    fn show_status(
+        &mut self,
+        location: &str,
+        mode: StatusLineMode,
+        a: i16,
+        b: u16,
+    ) -> Result<(), DisplayError> {
+        self.show_status(location, mode, a, b)
             .map_err(DisplayError::new)
     }
 
@@ -365,10 +507,19 @@ impl ZMachineDisplay for RatatuiDisplay {
         self.set_text_style(style).map_err(DisplayError::new)
     }
 
+    fn set_colour(&mut self, foreground: u16, background: u16) -> Result<(), DisplayError> {
+        self.set_colour(foreground, background)
+            .map_err(DisplayError::new)
+    }
+
     fn print_input_echo(&mut self, text: &str) -> Result<(), DisplayError> {
         // Input echo uses standard print - display thread handles timing
         self.print(text).map_err(DisplayError::new)
     }
+
+    fn set_input_active(&mut self, active: bool) -> Result<(), DisplayError> {
+        self.set_input_active(active).map_err(DisplayError::new)
+    }
 }
 
 impl Drop for RatatuiDisplay {
@@ -401,14 +552,17 @@ fn run_display_thread(rx: Receiver<DisplayCommand>) -> Result<(), Box<dyn std::e
         upper_window_lines: 0,
         current_window: 0,
         upper_window_content: vec![],
-        lower_window_content: vec![],
+        lower_window_content: VecDeque::new(),
         upper_cursor_x: 0,
         upper_cursor_y: 0,
-        lower_current_line: String::new(),
-        text_style: Style::default(),
+        lower_current_line: Vec::new(),
+        scroll_position: 0,
+        current_attrs: CellAttrs::default(),
+        current_fg: None,
+        current_bg: None,
         terminal_width: 0,
         terminal_height: 0,
-        reverse_video_active: false,
+        input_active: false,
     };
 
     // Get initial terminal size with fallback
@@ -450,19 +604,40 @@ fn run_display_thread(rx: Receiver<DisplayCommand>) -> Result<(), Box<dyn std::e
             }
         }
 
-        // Render only if we processed commands
-        if should_render {
-            state.render()?;
-        } else {
-            // Check for terminal resize events if no commands were processed
-            if event::poll(Duration::from_millis(0))? {
-                if let Event::Resize(width, height) = event::read()? {
+        // Check for terminal resize and scrollback navigation key events, regardless of
+        // whether channel commands were processed this tick. Skipped entirely while an
+        // interactive sread/read_char loop is active: crossterm's event queue has only
+        // one consumer, so polling here would race that loop for keystrokes (see
+        // `DisplayCommand::SetInputActive`).
+        if !state.input_active && event::poll(Duration::from_millis(0))? {
+            match event::read()? {
+                Event::Resize(width, height) => {
                     state.terminal_width = width;
                     state.terminal_height = height;
-                    state.render()?;
+                    should_render = true;
+                }
+                Event::Key(key) => {
+                    use crossterm::event::KeyCode;
+                    let scroll_cmd = match key.code {
+                        KeyCode::PageUp => Some(DisplayCommand::ScrollPageUp),
+                        KeyCode::PageDown => Some(DisplayCommand::ScrollPageDown),
+                        KeyCode::Up => Some(DisplayCommand::ScrollLineUp),
+                        KeyCode::Down => Some(DisplayCommand::ScrollLineDown),
+                        KeyCode::End => Some(DisplayCommand::ScrollToBottom),
+                        _ => None,
+                    };
+                    if let Some(cmd) = scroll_cmd {
+                        handle_command(&mut state, cmd)?;
+                        should_render = true;
+                    }
                 }
+                _ => {}
             }
         }
+
+        if should_render {
+            state.render()?;
+        }
     }
 }
 
@@ -480,10 +655,7 @@ fn handle_command(
                 // Fill each line with spaces to ensure proper window separation
                 let mut line = Vec::new();
                 for _col_idx in 0..state.terminal_width {
-                    line.push(StyledChar {
-                        ch: ' ',
-                        reverse_video: false,
-                    }); // Use space character
+                    line.push(StyledChar::plain(' ')); // Use space character
                 }
                 state.upper_window_content.push(line);
             }
@@ -497,13 +669,7 @@ fn handle_command(
 
                 // Auto-expand upper window if cursor positioned beyond bounds (error recovery)
                 while target_line >= state.upper_window_content.len() {
-                    let mut new_line = Vec::new();
-                    for _ in 0..state.terminal_width {
-                        new_line.push(StyledChar {
-                            ch: ' ',
-                            reverse_video: false,
-                        });
-                    }
+                    let new_line = vec![StyledChar::plain(' '); state.terminal_width as usize];
                     state.upper_window_content.push(new_line);
                     state.upper_window_lines += 1;
                 }
@@ -528,13 +694,8 @@ fn handle_command(
 
                         // Auto-expand upper window if needed (error recovery per Z-Machine spec)
                         while current_y >= state.upper_window_content.len() {
-                            let mut new_line = Vec::new();
-                            for _ in 0..state.terminal_width {
-                                new_line.push(StyledChar {
-                                    ch: ' ',
-                                    reverse_video: false,
-                                }); // Use space character
-                            }
+                            let new_line =
+                                vec![StyledChar::plain(' '); state.terminal_width as usize];
                             state.upper_window_content.push(new_line);
                             state.upper_window_lines += 1;
                         }
@@ -544,13 +705,8 @@ fn handle_command(
                     } else {
                         // Auto-expand upper window if needed (error recovery per Z-Machine spec)
                         while current_y >= state.upper_window_content.len() {
-                            let mut new_line = Vec::new();
-                            for _ in 0..state.terminal_width {
-                                new_line.push(StyledChar {
-                                    ch: ' ',
-                                    reverse_video: false,
-                                }); // Use space character
-                            }
+                            let new_line =
+                                vec![StyledChar::plain(' '); state.terminal_width as usize];
                             state.upper_window_content.push(new_line);
                             state.upper_window_lines += 1;
                         }
@@ -561,16 +717,16 @@ fn handle_command(
 
                             // Ensure line is long enough with spaces
                             while line.len() <= current_x {
-                                line.push(StyledChar {
-                                    ch: ' ',
-                                    reverse_video: false,
-                                });
+                                line.push(StyledChar::plain(' '));
                             }
 
-                            // Place styled character at cursor position
+                            // Place styled character at cursor position, stamped with the
+                            // current attributes/colours
                             let styled_char = StyledChar {
                                 ch,
-                                reverse_video: state.reverse_video_active,
+                                attrs: state.current_attrs,
+                                fg: state.current_fg,
+                                bg: state.current_bg,
                             };
 
                             if current_x < line.len() {
@@ -593,12 +749,21 @@ fn handle_command(
                 debug!("Lower window: adding text '{}'", text);
 
                 // Handle newlines and control characters in text
+                let attrs = state.current_attrs;
+                let fg = state.current_fg;
+                let bg = state.current_bg;
                 if text.contains('\n') {
                     let parts: Vec<&str> = text.split('\n').collect();
 
                     // Add first part to current line with backspace processing
                     if !parts.is_empty() {
-                        process_text_with_backspace(&mut state.lower_current_line, parts[0]);
+                        process_text_with_backspace(
+                            &mut state.lower_current_line,
+                            parts[0],
+                            attrs,
+                            fg,
+                            bg,
+                        );
                     }
 
                     // For each newline, finish current line and start new ones
@@ -606,22 +771,36 @@ fn handle_command(
                         // Finish current line and add to content
                         state
                             .lower_window_content
-                            .push(state.lower_current_line.clone());
+                            .push_back(state.lower_current_line.clone());
                         state.lower_current_line.clear();
 
                         // Start new line with this part (with backspace processing)
-                        process_text_with_backspace(&mut state.lower_current_line, part);
+                        process_text_with_backspace(
+                            &mut state.lower_current_line,
+                            part,
+                            attrs,
+                            fg,
+                            bg,
+                        );
                     }
                 } else {
                     // No newlines - add to current line with backspace processing
-                    process_text_with_backspace(&mut state.lower_current_line, &text);
+                    process_text_with_backspace(
+                        &mut state.lower_current_line,
+                        &text,
+                        attrs,
+                        fg,
+                        bg,
+                    );
                 }
 
-                // Keep scrolling buffer reasonable
-                let max_lines = (state.terminal_height - state.upper_window_lines) as usize;
-                if state.lower_window_content.len() > max_lines * 3 {
-                    state.lower_window_content.drain(0..max_lines);
+                // Bound the scrollback ring buffer
+                while state.lower_window_content.len() > SCROLLBACK_LINES {
+                    state.lower_window_content.pop_front();
                 }
+
+                // New output snaps the viewport back to the bottom
+                state.scroll_position = 0;
             }
         }
         DisplayCommand::PrintChar(ch) => {
@@ -637,6 +816,7 @@ fn handle_command(
                     state.upper_window_content.clear();
                     state.lower_window_content.clear();
                     state.lower_current_line.clear(); // <- This line prevents holdover text
+                    state.scroll_position = 0;
                     for _ in 0..state.upper_window_lines {
                         state.upper_window_content.push(Vec::new());
                     }
@@ -645,6 +825,7 @@ fn handle_command(
                     // Clear lower window - this should completely reset the text flow
                     state.lower_window_content.clear();
                     state.lower_current_line.clear();
+                    state.scroll_position = 0;
                     debug!(
                         "Lower window cleared - removed {} lines and current line",
                         state.lower_window_content.len()
@@ -655,10 +836,7 @@ fn handle_command(
                     for line in state.upper_window_content.iter_mut() {
                         line.clear();
                         for _ in 0..state.terminal_width {
-                            line.push(StyledChar {
-                                ch: ' ',
-                                reverse_video: false,
-                            });
+                            line.push(StyledChar::plain(' '));
                         }
                     }
                     state.upper_cursor_x = 0;
@@ -667,40 +845,32 @@ fn handle_command(
                 _ => {}
             }
         }
-        DisplayCommand::ShowStatus(location, score, moves) => {
+        DisplayCommand::ShowStatus(location, mode, a, b) => {
             if !state.upper_window_content.is_empty() {
-                let status = format_status_line(&location, score, moves, state.terminal_width);
-                // Convert string to styled chars (status line is not reversed)
-                let styled_chars: Vec<StyledChar> = status
-                    .chars()
-                    .map(|ch| StyledChar {
-                        ch,
-                        reverse_video: false,
-                    })
-                    .collect();
+                let status = format_status_line(&location, mode, a, b, state.terminal_width);
+                // Status line text is plain; it's not affected by the game's current style
+                let styled_chars: Vec<StyledChar> = status.chars().map(StyledChar::plain).collect();
                 state.upper_window_content[0] = styled_chars;
             }
         }
         DisplayCommand::SetTextStyle(style_bits) => {
-            let mut style = Style::default();
-            if style_bits & 1 != 0 {
-                style = style.add_modifier(Modifier::REVERSED);
-                state.reverse_video_active = true;
-            } else {
-                state.reverse_video_active = false;
-            }
-            if style_bits & 2 != 0 {
-                style = style.add_modifier(Modifier::BOLD);
+            state.current_attrs = CellAttrs::from_style_bits(style_bits);
+        }
+        DisplayCommand::SetColour(foreground, background) => {
+            // 1 = "current" per the Z-Machine set_colour spec: leave that operand's
+            // colour unchanged rather than overwriting it.
+            if foreground != 1 {
+                state.current_fg = Some(ZColor(foreground));
             }
-            if style_bits & 4 != 0 {
-                style = style.add_modifier(Modifier::ITALIC);
+            if background != 1 {
+                state.current_bg = Some(ZColor(background));
             }
-            state.text_style = style;
         }
         DisplayCommand::ClearScreen => {
             state.upper_window_content.clear();
             state.lower_window_content.clear();
             state.lower_current_line.clear();
+            state.scroll_position = 0;
             // Don't restore upper window lines here - split_window will create the correct number
         }
         DisplayCommand::EraseLine => {
@@ -717,29 +887,164 @@ fn handle_command(
             }
             // Lower window uses streaming - no cursor-based line erasing
         }
+        DisplayCommand::ScrollLineUp => {
+            state.scroll_position = state.scroll_position.saturating_add(1);
+        }
+        DisplayCommand::ScrollLineDown => {
+            state.scroll_position = state.scroll_position.saturating_sub(1);
+        }
+        DisplayCommand::ScrollPageUp => {
+            let page = lower_window_height(state).max(1);
+            state.scroll_position = state.scroll_position.saturating_add(page);
+        }
+        DisplayCommand::ScrollPageDown => {
+            let page = lower_window_height(state).max(1);
+            state.scroll_position = state.scroll_position.saturating_sub(page);
+        }
+        DisplayCommand::ScrollToBottom => {
+            state.scroll_position = 0;
+        }
+        DisplayCommand::SetInputActive(active) => {
+            state.input_active = active;
+        }
         _ => {}
     }
     Ok(())
 }
 
-/// Format the status line
-fn format_status_line(location: &str, score: i16, moves: u16, width: u16) -> String {
-    let right_text = format!("Score: {score} Moves: {moves}");
-    let available_width = width as usize;
-    let right_len = right_text.len();
+/// Height in rows available to the lower window (terminal height minus any upper window)
+fn lower_window_height(state: &DisplayState) -> usize {
+    state
+        .terminal_height
+        .saturating_sub(state.upper_window_lines) as usize
+}
 
-    // Truncate location if needed
-    let location_max_len = available_width.saturating_sub(right_len + 2);
-    let location_display = if location.len() > location_max_len {
-        &location[..location_max_len]
-    } else {
-        location
+/// Word-wrap one logical line into display rows no wider than `width` columns.
+///
+/// Breaks at the last space before the column limit is reached (the space itself is
+/// dropped, matching standard terminal word wrap) and falls back to a hard character
+/// break only for a single token that is wider than `width` on its own (e.g.
+/// "pneumonoultramicroscopicsilicovolcanoconiosis" in a narrow window). Column widths
+/// are computed with `unicode-width` so multi-byte and wide (CJK) characters count
+/// correctly instead of assuming one column per `char`.
+fn word_wrap_styled(line: &[StyledChar], width: usize) -> Vec<Vec<StyledChar>> {
+    let width = width.max(1);
+
+    // Split into words (maximal runs of non-space) and single-space separators,
+    // preserving each character's style.
+    let mut words: Vec<Vec<StyledChar>> = Vec::new();
+    let mut current: Vec<StyledChar> = Vec::new();
+    for ch in line {
+        if ch.ch == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            words.push(vec![ch.clone()]);
+        } else {
+            current.push(ch.clone());
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let mut rows: Vec<Vec<StyledChar>> = vec![Vec::new()];
+    let mut row_width = 0usize;
+
+    for word in words {
+        let is_space = word.len() == 1 && word[0].ch == ' ';
+        let word_width: usize = word.iter().map(|c| c.ch.width().unwrap_or(0)).sum();
+
+        if is_space {
+            if row_width > 0 && row_width + word_width <= width {
+                rows.last_mut().unwrap().extend(word);
+                row_width += word_width;
+            } else if row_width == 0 {
+                // Leading space on an otherwise-empty row: keep it.
+                rows.last_mut().unwrap().extend(word);
+                row_width += word_width;
+            }
+            // Otherwise the space falls exactly at a wrap point and is dropped.
+            continue;
+        }
+
+        if row_width > 0 && row_width + word_width > width {
+            rows.push(Vec::new());
+            row_width = 0;
+        }
+
+        if word_width > width {
+            // Hard-break an over-long token across as many rows as it needs.
+            for ch in word {
+                let w = ch.ch.width().unwrap_or(0);
+                if row_width > 0 && row_width + w > width {
+                    rows.push(Vec::new());
+                    row_width = 0;
+                }
+                rows.last_mut().unwrap().push(ch);
+                row_width += w;
+            }
+        } else {
+            rows.last_mut().unwrap().extend(word);
+            row_width += word_width;
+        }
+    }
+
+    rows
+}
+
+/// Build a ratatui `Line` out of a styled lower-window row, grouping consecutive
+/// characters that share the same style into a single `Span` so mid-line style
+/// changes (bold verbs, reversed prompts, colour) survive scrolling and wrapping.
+fn styled_line(chars: &[StyledChar]) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_style: Option<Style> = None;
+
+    for styled_char in chars {
+        let style = styled_char.to_style();
+        match run_style {
+            Some(current) if current == style => run.push(styled_char.ch),
+            Some(current) => {
+                spans.push(Span::styled(std::mem::take(&mut run), current));
+                run.push(styled_char.ch);
+                run_style = Some(style);
+            }
+            None => {
+                run.push(styled_char.ch);
+                run_style = Some(style);
+            }
+        }
+    }
+    if let Some(style) = run_style {
+        spans.push(Span::styled(run, style));
+    }
+
+    Line::from(spans)
+}
+
+/// Format the status line. `mode` selects the V1-3 status-line layout: score/moves or,
+/// for "time" games (header Flags 1 bit 1), a 12-hour clock built from `a`/`b`.
+///
+/// Truncation and padding are computed in display columns (via `unicode-width`), not
+/// bytes, so a multi-byte location name can't panic on a non-char-boundary slice and
+/// wide (e.g. CJK) characters are counted as the columns they actually occupy.
+fn format_status_line(location: &str, mode: StatusLineMode, a: i16, b: u16, width: u16) -> String {
+    let right_text = match mode {
+        StatusLineMode::Score => format!("Score: {a} Moves: {b}"),
+        StatusLineMode::Time => crate::display_trait::format_time_12h(a, b),
     };
+    let available_width = width as usize;
+    let right_width = right_text.width();
+
+    // Truncate location if needed, at a display-column boundary
+    let location_max_width = available_width.saturating_sub(right_width + 2);
+    let location_display = truncate_to_width(location, location_max_width);
 
     // Build status line with padding
     let padding_len = available_width
-        .saturating_sub(location_display.len())
-        .saturating_sub(right_len);
+        .saturating_sub(location_display.width())
+        .saturating_sub(right_width);
 
     format!(
         "{}{:padding$}{}",
@@ -750,16 +1055,23 @@ fn format_status_line(location: &str, score: i16, moves: u16, width: u16) -> Str
     )
 }
 
-/// Process text with backspace characters, properly removing characters
-/// This handles the backspace sequence "\x08 \x08" sent by input handlers
-fn process_text_with_backspace(buffer: &mut String, text: &str) {
+/// Process text with backspace characters, properly removing characters.
+/// This handles the backspace sequence "\x08 \x08" sent by input handlers. Newly
+/// appended characters are stamped with the given attributes/colours.
+fn process_text_with_backspace(
+    buffer: &mut Vec<StyledChar>,
+    text: &str,
+    attrs: CellAttrs,
+    fg: Option<ZColor>,
+    bg: Option<ZColor>,
+) {
     for ch in text.chars() {
         if ch == '\x08' {
             // Backspace - remove last character
             buffer.pop();
         } else {
-            // Regular character - add to buffer
-            buffer.push(ch);
+            // Regular character - add to buffer, stamped with current style
+            buffer.push(StyledChar { ch, attrs, fg, bg });
         }
     }
 }
@@ -794,11 +1106,15 @@ impl DisplayState {
                         for (col_idx, styled_char) in styled_line.iter().enumerate() {
                             if col_idx < chunks[0].width as usize {
                                 let x = chunks[0].x + col_idx as u16;
-                                let style = if styled_char.reverse_video {
-                                    Style::default().add_modifier(Modifier::REVERSED)
-                                } else {
-                                    // Use normal colors for all characters
+                                // Unstyled cells keep the window's normal colors; styled
+                                // cells (reverse/bold/italic/fg/bg) render via to_style()
+                                let style = if styled_char.attrs == CellAttrs::default()
+                                    && styled_char.fg.is_none()
+                                    && styled_char.bg.is_none()
+                                {
                                     Style::default().fg(Color::White).bg(Color::Black)
+                                } else {
+                                    styled_char.to_style()
                                 };
                                 f.buffer_mut()
                                     .get_mut(x, y)
@@ -818,31 +1134,29 @@ impl DisplayState {
                 lower_lines.push(self.lower_current_line.clone());
             }
 
-            let lower_text: Vec<Line> =
-                lower_lines.iter().map(|s| Line::from(s.as_str())).collect();
-
-            // Calculate scroll to keep all content visible, accounting for word wrapping
-            // CRITICAL FIX (v0.5.0): This calculation now accounts for ratatui's automatic
-            // word wrapping, which can cause logical lines to span multiple display lines.
-            // Previous versions only counted logical lines, causing input prompts to be
-            // lost below the viewport in games like AMFV when content filled small terminals.
-            // Note: The prompt is part of the game content, not a separate UI element
+            // CRITICAL FIX (v0.5.0, revised): word-wrap every logical line ourselves using
+            // true display-column widths (via `unicode-width`), so the row count used for
+            // `scroll_offset` exactly matches what's rendered below - no more counting by
+            // byte length, which mis-positioned the input prompt for any multi-byte or
+            // wide (e.g. CJK) content. Rows are pre-wrapped at word boundaries (falling back
+            // to a hard break for a single over-long token, the classic
+            // `pneumonoultramicroscopicsilicovolcanoconiosis` case) before handing them to
+            // ratatui, so ratatui's own `Wrap` never has to make a different wrap decision.
             let available_lines = chunks[1].height as usize;
             let available_width = chunks[1].width as usize;
 
-            // Calculate actual display lines after word wrapping
-            let mut total_display_lines = 0;
+            let mut display_rows: Vec<Vec<StyledChar>> = Vec::new();
             for line in &lower_lines {
-                if line.is_empty() {
-                    total_display_lines += 1;
-                } else {
-                    // Calculate wrapped line count
-                    let wrapped_lines = (line.len() + available_width - 1) / available_width.max(1);
-                    total_display_lines += wrapped_lines.max(1);
-                }
+                display_rows.extend(word_wrap_styled(line, available_width));
             }
 
-            let scroll_offset = total_display_lines.saturating_sub(available_lines);
+            let total_display_lines = display_rows.len();
+            let max_scroll = total_display_lines.saturating_sub(available_lines);
+            let effective_scroll = self.scroll_position.min(max_scroll);
+            let scroll_offset =
+                total_display_lines.saturating_sub(available_lines + effective_scroll);
+
+            let lower_text: Vec<Line> = display_rows.iter().map(|row| styled_line(row)).collect();
 
             let lower_paragraph = Paragraph::new(lower_text)
                 .wrap(Wrap { trim: false }) // Don't trim - preserve spaces!
@@ -850,6 +1164,26 @@ impl DisplayState {
                 .scroll((scroll_offset as u16, 0));
 
             f.render_widget(lower_paragraph, chunks[1]);
+
+            // Subtle indicator that the viewport is scrolled back from the latest output,
+            // so it isn't mistaken for the game having stalled.
+            if effective_scroll > 0 && chunks[1].width > 0 && chunks[1].height > 0 {
+                let label = format!(" SCROLLED ({effective_scroll}) - End to return ");
+                let label_width = label.chars().count() as u16;
+                let x = chunks[1]
+                    .x
+                    .saturating_add(chunks[1].width.saturating_sub(label_width));
+                let y = chunks[1].y;
+                f.buffer_mut().set_string(
+                    x,
+                    y,
+                    &label,
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
         })?;
 
         Ok(())
@@ -860,30 +1194,53 @@ impl DisplayState {
 mod tests {
     use super::*;
 
+    fn chars_of(buffer: &[StyledChar]) -> String {
+        buffer.iter().map(|c| c.ch).collect()
+    }
+
     #[test]
     fn test_process_text_with_backspace() {
-        let mut buffer = String::new();
+        let mut buffer: Vec<StyledChar> = Vec::new();
 
         // Test normal text
-        process_text_with_backspace(&mut buffer, "Hello");
-        assert_eq!(buffer, "Hello");
+        process_text_with_backspace(&mut buffer, "Hello", CellAttrs::default(), None, None);
+        assert_eq!(chars_of(&buffer), "Hello");
 
         // Test backspace removing character
-        process_text_with_backspace(&mut buffer, "\x08");
-        assert_eq!(buffer, "Hell");
+        process_text_with_backspace(&mut buffer, "\x08", CellAttrs::default(), None, None);
+        assert_eq!(chars_of(&buffer), "Hell");
 
         // Test backspace sequence like input handlers send: "\x08 \x08"
-        process_text_with_backspace(&mut buffer, "\x08 \x08");
-        assert_eq!(buffer, "Hel"); // First \x08 removes 'l', space adds ' ', second \x08 removes ' '
+        process_text_with_backspace(&mut buffer, "\x08 \x08", CellAttrs::default(), None, None);
+        assert_eq!(chars_of(&buffer), "Hel"); // First \x08 removes 'l', space adds ' ', second \x08 removes ' '
 
         // Test backspace on empty buffer (should be safe)
         buffer.clear();
-        process_text_with_backspace(&mut buffer, "\x08");
-        assert_eq!(buffer, "");
+        process_text_with_backspace(&mut buffer, "\x08", CellAttrs::default(), None, None);
+        assert_eq!(chars_of(&buffer), "");
 
         // Test mixed text and backspaces
         buffer.clear();
-        process_text_with_backspace(&mut buffer, "AB\x08C");
-        assert_eq!(buffer, "AC"); // AB, backspace removes B, C is added
+        process_text_with_backspace(&mut buffer, "AB\x08C", CellAttrs::default(), None, None);
+        assert_eq!(chars_of(&buffer), "AC"); // AB, backspace removes B, C is added
+    }
+
+    #[test]
+    fn test_word_wrap_styled_breaks_at_spaces() {
+        let line: Vec<StyledChar> = "hello world".chars().map(StyledChar::plain).collect();
+        let rows = word_wrap_styled(&line, 5);
+        let rendered: Vec<String> = rows.iter().map(|r| chars_of(r)).collect();
+        assert_eq!(rendered, vec!["hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn test_word_wrap_styled_hard_breaks_long_token() {
+        let line: Vec<StyledChar> = "abcdefgh".chars().map(StyledChar::plain).collect();
+        let rows = word_wrap_styled(&line, 3);
+        let rendered: Vec<String> = rows.iter().map(|r| chars_of(r)).collect();
+        assert_eq!(
+            rendered,
+            vec!["abc".to_string(), "def".to_string(), "gh".to_string()]
+        );
     }
 }
@@ -5,6 +5,16 @@
 
 use std::fmt;
 
+/// Which layout `show_status` should render: V1-3 games pick one at assembly time via
+/// the header's Flags 1 bit 1, and it never changes mid-game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusLineMode {
+    /// `Score: {a} Moves: {b}`
+    Score,
+    /// `{a}:{b} (AM/PM)`, a 12-hour clock built from hours (`a`) and minutes (`b`)
+    Time,
+}
+
 /// Core trait for Z-Machine display operations
 pub trait ZMachineDisplay {
     /// Clear the entire screen
@@ -38,8 +48,18 @@ pub trait ZMachineDisplay {
     // V3-specific operations (no-op for v4+)
 
     /// Show status line (v3 only)
-    /// For v4+, this should be a no-op as games manage their own status
-    fn show_status(&mut self, location: &str, score: i16, moves: u16) -> Result<(), DisplayError>;
+    /// For v4+, this should be a no-op as games manage their own status.
+    ///
+    /// `mode` selects how `a`/`b` are interpreted: [`StatusLineMode::Score`] means
+    /// score/moves, [`StatusLineMode::Time`] means hours/minutes (a V1-3 "time" game,
+    /// per the header's Flags 1 bit 1).
+    fn show_status(
+        &mut self,
+        location: &str,
+        mode: StatusLineMode,
+        a: i16,
+        b: u16,
+    ) -> Result<(), DisplayError>;
 
     // V4+ specific operations (no-op or error for v3)
 
@@ -63,6 +83,23 @@ pub trait ZMachineDisplay {
         self.print(text)
     }
 
+    /// Mark whether an interactive `sread`/`read_char` loop currently owns the terminal
+    /// event stream. Implementations that poll crossterm on their own (e.g. the ratatui
+    /// display's background render thread) use this to stay off the stream while input
+    /// is active, since crossterm's event queue has only one consumer: whichever side
+    /// calls `event::read()` first permanently removes the event from the other. Display
+    /// backends that don't compete for terminal events can ignore this.
+    fn set_input_active(&mut self, _active: bool) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
+    /// Set current foreground/background colours (Z-Machine `set_colour`/true-colour
+    /// opcodes). Each is a standard Z-Machine colour number (1 = current, 2 = default,
+    /// 3-13 = the named palette). Implementations without colour support can ignore this.
+    fn set_colour(&mut self, _foreground: u16, _background: u16) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
     // Utility methods
 
     /// Get the current terminal dimensions
@@ -72,6 +109,40 @@ pub trait ZMachineDisplay {
     fn force_refresh(&mut self) -> Result<(), DisplayError>;
 }
 
+/// Format a V1-3 "time" game's clock (hours 0-23, minutes 0-59, per globals G17/G18)
+/// as a 12-hour `HH:MM AM/PM` string, the layout the Z-Machine spec prescribes for the
+/// time status line.
+pub fn format_time_12h(hours: i16, minutes: u16) -> String {
+    let hours = hours.rem_euclid(24);
+    let period = if hours < 12 { "AM" } else { "PM" };
+    let hour_12 = match hours % 12 {
+        0 => 12,
+        h => h,
+    };
+    format!("{hour_12}:{minutes:02} {period}")
+}
+
+/// Truncate `s` to at most `max_width` display columns, breaking only on a char
+/// boundary (never mid-codepoint) and counting wide characters as more than one column.
+pub fn truncate_to_width(s: &str, max_width: usize) -> &str {
+    use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+    if s.width() <= max_width {
+        return s;
+    }
+    let mut width = 0;
+    let mut end = s.len();
+    for (idx, ch) in s.char_indices() {
+        let ch_width = ch.width().unwrap_or(0);
+        if width + ch_width > max_width {
+            end = idx;
+            break;
+        }
+        width += ch_width;
+    }
+    &s[..end]
+}
+
 /// Display error type
 #[derive(Debug, Clone)]
 pub struct DisplayError {
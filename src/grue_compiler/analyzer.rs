@@ -0,0 +1,486 @@
+// Type-checking analysis pass for Grue language
+//
+// This runs after parsing and alongside `semantic::SemanticAnalyzer`. Unlike
+// `SemanticAnalyzer`, which stops at the first error, `Analyzer` walks the
+// whole `Program` and accumulates every problem it finds into a `Vec`, so
+// callers (and eventually diagnostics tooling) can report more than one
+// mistake per compile.
+
+use crate::grue_compiler::ast::*;
+use crate::grue_compiler::error::CompilerError;
+use crate::grue_compiler::semantic::builtin_function_signatures;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+struct FunctionSignature {
+    params: Vec<Type>,
+    return_type: Option<Type>,
+}
+
+pub struct Analyzer {
+    rooms: HashSet<String>,
+    objects: HashSet<String>,
+    functions: HashMap<String, FunctionSignature>,
+    locals: Vec<HashMap<String, Type>>,
+    errors: Vec<CompilerError>,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Analyzer {
+    pub fn new() -> Self {
+        let mut functions = HashMap::new();
+        for (name, params, return_type) in builtin_function_signatures() {
+            functions.insert(
+                name.to_string(),
+                FunctionSignature {
+                    params,
+                    return_type,
+                },
+            );
+        }
+
+        Analyzer {
+            rooms: HashSet::new(),
+            objects: HashSet::new(),
+            functions,
+            locals: vec![HashMap::new()],
+            errors: Vec::new(),
+        }
+    }
+
+    /// Walk `program`, collecting every type error it can find instead of
+    /// stopping at the first one.
+    pub fn analyze(mut self, program: &Program) -> Vec<CompilerError> {
+        self.collect_symbols(program);
+
+        for item in &program.items {
+            match item {
+                Item::World(world) => self.check_world(world),
+                Item::Grammar(grammar) => self.check_grammar(grammar),
+                Item::Function(func) => self.check_function(func),
+                Item::Init(init) => {
+                    self.push_scope();
+                    self.check_block(&init.body);
+                    self.pop_scope();
+                }
+                Item::Mode(_) => {}
+            }
+        }
+
+        self.errors
+    }
+
+    fn collect_symbols(&mut self, program: &Program) {
+        for item in &program.items {
+            match item {
+                Item::World(world) => {
+                    for room in &world.rooms {
+                        self.rooms.insert(room.identifier.clone());
+                        self.collect_object_symbols(&room.objects);
+                    }
+                }
+                Item::Function(func) => {
+                    let params = func
+                        .parameters
+                        .iter()
+                        .map(|p| p.param_type.clone().unwrap_or(Type::Any))
+                        .collect();
+                    self.functions.insert(
+                        func.name.clone(),
+                        FunctionSignature {
+                            params,
+                            return_type: func.return_type.clone(),
+                        },
+                    );
+                }
+                Item::Grammar(_) | Item::Init(_) | Item::Mode(_) => {}
+            }
+        }
+    }
+
+    fn collect_object_symbols(&mut self, objects: &[ObjectDecl]) {
+        for object in objects {
+            self.objects.insert(object.identifier.clone());
+            self.collect_object_symbols(&object.contains);
+        }
+    }
+
+    fn push_scope(&mut self) {
+        self.locals.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.locals.pop();
+    }
+
+    fn declare_local(&mut self, name: &str, ty: Type) {
+        self.locals
+            .last_mut()
+            .expect("Analyzer always has at least one scope")
+            .insert(name.to_string(), ty);
+    }
+
+    fn lookup_local(&self, name: &str) -> Option<Type> {
+        self.locals
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).cloned())
+    }
+
+    fn check_world(&mut self, world: &WorldDecl) {
+        for room in &world.rooms {
+            self.check_room(room);
+        }
+    }
+
+    fn check_room(&mut self, room: &RoomDecl) {
+        for (direction, target) in &room.exits {
+            if let ExitTarget::Room(target_room) = target {
+                if !self.rooms.contains(target_room) {
+                    self.errors.push(CompilerError::SemanticError(
+                        format!(
+                            "Exit '{}' references undefined room '{}'",
+                            direction, target_room
+                        ),
+                        room.span.start,
+                    ));
+                }
+            }
+        }
+
+        self.push_scope();
+        for block in [&room.on_enter, &room.on_exit, &room.on_look]
+            .into_iter()
+            .flatten()
+        {
+            self.check_block(block);
+        }
+        self.pop_scope();
+
+        self.check_objects(&room.objects);
+    }
+
+    fn check_objects(&mut self, objects: &[ObjectDecl]) {
+        for object in objects {
+            if !matches!(object.description, Expr::String(_)) {
+                self.errors.push(CompilerError::SemanticError(
+                    format!(
+                        "object '{}' description must be a string literal",
+                        object.identifier
+                    ),
+                    object.span.start,
+                ));
+            }
+
+            for value in object
+                .properties
+                .values()
+                .chain(object.numbered_properties.values())
+            {
+                self.check_property_value(value, object.span.start);
+            }
+
+            self.check_objects(&object.contains);
+        }
+    }
+
+    /// Type-check a single object property value: validate that `Object`/
+    /// `Room` references (and bare identifiers left in a `PropertyValue::Expr`,
+    /// which IR generation resolves to an object number) actually name a
+    /// declared object or room, recursing into array elements.
+    fn check_property_value(&mut self, value: &PropertyValue, pos: usize) {
+        match value {
+            PropertyValue::Object(name) => {
+                if !self.objects.contains(name) {
+                    self.errors
+                        .push(CompilerError::UndefinedSymbol(name.clone(), pos));
+                }
+            }
+            PropertyValue::Room(name) => {
+                if !self.rooms.contains(name) {
+                    self.errors
+                        .push(CompilerError::UndefinedSymbol(name.clone(), pos));
+                }
+            }
+            PropertyValue::Array(elements) => {
+                for element in elements {
+                    self.check_property_value(element, pos);
+                }
+            }
+            PropertyValue::Expr(expr) => {
+                self.check_expression(expr);
+                if let Expr::Identifier(name) = expr {
+                    if !self.objects.contains(name) && !self.rooms.contains(name) {
+                        self.errors
+                            .push(CompilerError::UndefinedSymbol(name.clone(), pos));
+                    }
+                }
+            }
+            PropertyValue::Boolean(_)
+            | PropertyValue::Integer(_)
+            | PropertyValue::String(_)
+            | PropertyValue::Byte(_)
+            | PropertyValue::Bytes(_) => {}
+        }
+    }
+
+    fn check_grammar(&mut self, grammar: &GrammarDecl) {
+        for verb in &grammar.verbs {
+            for pattern in &verb.patterns {
+                if let Handler::FunctionCall(name, arguments) = &pattern.handler {
+                    self.check_call_arity(name, arguments.len(), verb.span.start);
+                }
+                if let Handler::Block(block) = &pattern.handler {
+                    self.push_scope();
+                    self.check_block(block);
+                    self.pop_scope();
+                }
+            }
+        }
+    }
+
+    fn check_function(&mut self, func: &FunctionDecl) {
+        self.push_scope();
+        for param in &func.parameters {
+            self.declare_local(&param.name, param.param_type.clone().unwrap_or(Type::Any));
+        }
+        self.check_block(&func.body);
+        self.pop_scope();
+    }
+
+    fn check_block(&mut self, block: &BlockStmt) {
+        self.push_scope();
+        for stmt in &block.statements {
+            self.check_statement(stmt);
+        }
+        self.pop_scope();
+    }
+
+    fn check_statement(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.check_expression(expr);
+            }
+            Stmt::VarDecl(var_decl) => self.check_var_decl(var_decl),
+            Stmt::Assignment(assignment) => {
+                self.check_expression(&assignment.value);
+                self.check_expression(&assignment.target);
+            }
+            Stmt::If(if_stmt) => {
+                self.check_condition(&if_stmt.condition, if_stmt.span.start);
+                self.check_statement(&if_stmt.then_branch);
+                if let Some(else_branch) = &if_stmt.else_branch {
+                    self.check_statement(else_branch);
+                }
+            }
+            Stmt::While(while_stmt) => {
+                self.check_condition(&while_stmt.condition, while_stmt.span.start);
+                self.check_statement(&while_stmt.body);
+            }
+            Stmt::For(for_stmt) => {
+                self.check_expression(&for_stmt.iterable);
+                self.push_scope();
+                self.declare_local(&for_stmt.variable, Type::Any);
+                self.check_statement(&for_stmt.body);
+                self.pop_scope();
+            }
+            Stmt::Return(value) => {
+                if let Some(value) = value {
+                    self.check_expression(value);
+                }
+            }
+            Stmt::Block(block) => self.check_block(block),
+        }
+    }
+
+    fn check_var_decl(&mut self, var_decl: &VarDeclStmt) {
+        if let Some(initializer) = &var_decl.initializer {
+            self.check_expression(initializer);
+
+            if let Some(declared) = &var_decl.var_type {
+                if let Some(inferred) = self.infer_type(initializer) {
+                    if !self.types_compatible(declared, &inferred) {
+                        self.errors.push(CompilerError::SemanticError(
+                            format!(
+                                "{} intended here, not {}",
+                                type_name(declared),
+                                type_name(&inferred)
+                            ),
+                            var_decl.span.start,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let var_type = var_decl
+            .var_type
+            .clone()
+            .or_else(|| {
+                var_decl
+                    .initializer
+                    .as_ref()
+                    .and_then(|e| self.infer_type(e))
+            })
+            .unwrap_or(Type::Any);
+        self.declare_local(&var_decl.name, var_type);
+    }
+
+    fn check_condition(&mut self, condition: &Expr, pos: usize) {
+        self.check_expression(condition);
+        if let Some(inferred) = self.infer_type(condition) {
+            if !self.types_compatible(&Type::Bool, &inferred) {
+                self.errors.push(CompilerError::SemanticError(
+                    format!("bool intended here, not {}", type_name(&inferred)),
+                    pos,
+                ));
+            }
+        }
+    }
+
+    fn check_expression(&mut self, expr: &Expr) {
+        match expr {
+            Expr::FunctionCall {
+                name,
+                arguments,
+                span,
+            } => {
+                for argument in arguments {
+                    self.check_expression(argument);
+                }
+                self.check_call_arity(name, arguments.len(), span.start);
+            }
+            Expr::MethodCall {
+                object, arguments, ..
+            } => {
+                self.check_expression(object);
+                for argument in arguments {
+                    self.check_expression(argument);
+                }
+            }
+            Expr::PropertyAccess { object, .. } | Expr::NullSafePropertyAccess { object, .. } => {
+                self.check_expression(object);
+            }
+            Expr::Binary { left, right, .. } => {
+                self.check_expression(left);
+                self.check_expression(right);
+            }
+            Expr::Unary { operand, .. } => self.check_expression(operand),
+            Expr::Array(elements) | Expr::MultipleObjects(elements) => {
+                for element in elements {
+                    self.check_expression(element);
+                }
+            }
+            Expr::Ternary {
+                condition,
+                true_expr,
+                false_expr,
+            } => {
+                self.check_expression(condition);
+                self.check_expression(true_expr);
+                self.check_expression(false_expr);
+            }
+            Expr::DisambiguationContext { candidates, .. } => {
+                for candidate in candidates {
+                    self.check_expression(candidate);
+                }
+            }
+            Expr::Boolean(_)
+            | Expr::Integer(_)
+            | Expr::String(_)
+            | Expr::Identifier(_)
+            | Expr::Parameter(_)
+            | Expr::ParsedObject { .. } => {}
+        }
+    }
+
+    fn check_call_arity(&mut self, name: &str, arg_count: usize, pos: usize) {
+        match self.functions.get(name) {
+            Some(signature) => {
+                if signature.params.len() != arg_count {
+                    self.errors.push(CompilerError::SemanticError(
+                        format!(
+                            "function '{}' expects {} argument(s), found {}",
+                            name,
+                            signature.params.len(),
+                            arg_count
+                        ),
+                        pos,
+                    ));
+                }
+            }
+            None => {
+                self.errors
+                    .push(CompilerError::UndefinedSymbol(name.to_string(), pos));
+            }
+        }
+    }
+
+    /// Infer the static type of `expr` when it can be determined without a
+    /// full type-inference pass - literals, known locals/builtins, and the
+    /// handful of operator shapes that always produce a `bool`. Returns
+    /// `None` when the expression's type can't be pinned down this way;
+    /// callers treat `None` as "nothing to check" rather than an error.
+    fn infer_type(&self, expr: &Expr) -> Option<Type> {
+        match expr {
+            Expr::Boolean(_) => Some(Type::Bool),
+            Expr::Integer(_) => Some(Type::Int),
+            Expr::String(_) => Some(Type::String),
+            Expr::Identifier(name) => self.lookup_local(name).or_else(|| {
+                if self.rooms.contains(name) {
+                    Some(Type::Room)
+                } else if self.objects.contains(name) {
+                    Some(Type::Object)
+                } else {
+                    None
+                }
+            }),
+            Expr::FunctionCall { name, .. } => self
+                .functions
+                .get(name)
+                .and_then(|sig| sig.return_type.clone()),
+            Expr::Unary {
+                operator: UnaryOp::Not,
+                ..
+            } => Some(Type::Bool),
+            Expr::Binary { operator, .. } => match operator {
+                BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Less
+                | BinaryOp::LessEqual
+                | BinaryOp::Greater
+                | BinaryOp::GreaterEqual
+                | BinaryOp::And
+                | BinaryOp::Or => Some(Type::Bool),
+                _ => None,
+            },
+            Expr::Ternary { true_expr, .. } => self.infer_type(true_expr),
+            _ => None,
+        }
+    }
+
+    fn types_compatible(&self, expected: &Type, actual: &Type) -> bool {
+        matches!((expected, actual), (Type::Any, _) | (_, Type::Any)) || expected == actual
+    }
+}
+
+fn type_name(ty: &Type) -> &'static str {
+    match ty {
+        Type::Any => "any",
+        Type::Bool => "bool",
+        Type::Int => "int",
+        Type::String => "string",
+        Type::Room => "room",
+        Type::Object => "object",
+        Type::Array(_) => "array",
+    }
+}
+
+#[cfg(test)]
+#[path = "analyzer_tests.rs"]
+mod tests;
@@ -0,0 +1,144 @@
+// Tests for the accumulating type-checking pass in `analyzer.rs`
+
+#[cfg(test)]
+mod analyzer_tests {
+    use crate::grue_compiler::analyzer::Analyzer;
+    use crate::grue_compiler::error::CompilerError;
+    use crate::grue_compiler::lexer::Lexer;
+    use crate::grue_compiler::parser::Parser;
+
+    fn analyze_input(input: &str) -> Vec<CompilerError> {
+        let mut lexer = Lexer::new(input);
+        let tokens = lexer.tokenize().expect("lexing should succeed");
+        let mut parser = Parser::new(tokens, input);
+        let ast = parser.parse().expect("parsing should succeed");
+        Analyzer::new().analyze(&ast)
+    }
+
+    #[test]
+    fn test_well_typed_program_has_no_errors() {
+        let input = r#"
+            fn greet(name: string) -> bool {
+                let excited: bool = true;
+                if (excited) {
+                    print(name);
+                }
+                return excited;
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_string_assigned_to_int_variable() {
+        let input = r#"
+            fn test() {
+                let count: int = "five";
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::SemanticError(msg, _) => {
+                assert!(msg.contains("int intended here, not string"));
+            }
+            other => panic!("expected a type mismatch error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_call_to_undefined_function() {
+        let input = r#"
+            fn test() {
+                do_the_thing();
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::UndefinedSymbol(name, _) => assert_eq!(name, "do_the_thing"),
+            other => panic!("expected an undefined symbol error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_call_with_wrong_argument_count() {
+        let input = r#"
+            fn add_one(value: int) -> int {
+                return value;
+            }
+
+            fn test() {
+                add_one(1, 2);
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::SemanticError(msg, _) => {
+                assert!(msg.contains("expects 1 argument(s), found 2"));
+            }
+            other => panic!("expected an arity error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_grammar_handler_calls_undefined_function() {
+        let input = r#"
+            grammar {
+                verb "frobnicate" {
+                    default => do_frobnicate()
+                }
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::UndefinedSymbol(name, _) => assert_eq!(name, "do_frobnicate"),
+            other => panic!("expected an undefined symbol error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_exit_references_undefined_room() {
+        let input = r#"
+            world {
+                room west_house "West of House" {
+                    desc: "You are standing in an open field."
+                    exits: {
+                        north: undefined_room
+                    }
+                }
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::SemanticError(msg, _) => {
+                assert!(msg.contains("undefined room 'undefined_room'"));
+            }
+            other => panic!("expected a semantic error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_bool_if_condition() {
+        let input = r#"
+            fn test() {
+                let count: int = 5;
+                if (count) {
+                    print("nonzero");
+                }
+            }
+        "#;
+        let errors = analyze_input(input);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            CompilerError::SemanticError(msg, _) => {
+                assert!(msg.contains("bool intended here, not int"));
+            }
+            other => panic!("expected a type mismatch error, got {:?}", other),
+        }
+    }
+}
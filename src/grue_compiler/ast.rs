@@ -3,6 +3,53 @@
 use indexmap::IndexMap;
 use std::collections::HashMap;
 
+/// A source-code span: the half-open character range an AST node (or a lexer
+/// token) was parsed from, plus the 1-based line/column of its start. This is
+/// what lets a `CompilerError` be rendered as a caret-underlined source
+/// snippet instead of just a raw character offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    /// Build a span covering `start..end`, deriving the 1-based line/column of
+    /// `start` by scanning `source` up to that offset.
+    pub fn new(source: &str, start: usize, end: usize) -> Self {
+        let (line, col) = Span::line_col(source, start);
+        Span {
+            start,
+            end,
+            line,
+            col,
+        }
+    }
+
+    /// Build a zero-width span at a single character offset, for diagnostics
+    /// (like most `CompilerError` variants) that only carry a position rather
+    /// than a consumed range.
+    pub fn from_position(source: &str, position: usize) -> Self {
+        Span::new(source, position, position)
+    }
+
+    fn line_col(source: &str, position: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut col = 1;
+        for ch in source.chars().take(position) {
+            if ch == '\n' {
+                line += 1;
+                col = 1;
+            } else {
+                col += 1;
+            }
+        }
+        (line, col)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Program {
     pub items: Vec<Item>,
@@ -80,6 +127,7 @@ pub enum ProgramMode {
 #[derive(Debug, Clone)]
 pub struct WorldDecl {
     pub rooms: Vec<RoomDecl>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -92,13 +140,14 @@ pub struct RoomDecl {
     pub on_enter: Option<BlockStmt>,
     pub on_exit: Option<BlockStmt>,
     pub on_look: Option<BlockStmt>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
 pub struct ObjectDecl {
     pub identifier: String,
     pub names: Vec<String>,
-    pub description: String,
+    pub description: Expr,
     pub properties: HashMap<String, PropertyValue>,
     pub attributes: Vec<String>, // Named attributes (e.g., "openable", "container")
     pub numbered_properties: HashMap<u8, PropertyValue>, // Z-Machine numbered properties
@@ -107,6 +156,8 @@ pub struct ObjectDecl {
     // Enhanced object system integration
     pub object_type: Option<ObjectTypeDecl>, // Optional explicit type declaration
     pub inheritance: Option<String>,         // Inherit from another object type
+
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -120,10 +171,17 @@ pub enum PropertyValue {
     Boolean(bool),
     Integer(i16),
     String(String),
-    Byte(u8),       // For numbered properties
-    Bytes(Vec<u8>), // For multi-byte numbered properties
-    Object(String), // Reference to another object
-    Room(String),   // Reference to a room
+    Byte(u8),                  // For numbered properties
+    Bytes(Vec<u8>),            // For multi-byte numbered properties
+    Object(String),            // Reference to another object
+    Room(String),              // Reference to a room
+    Array(Vec<PropertyValue>), // Array literal, e.g. `names: ["a", "b"]`
+    // Anything that didn't reduce to one of the variants above while parsing
+    // (an identifier reference, a function call, an arithmetic expression
+    // like `3 * 2`, ...). Left for the semantic analyzer / ast_optimizer to
+    // type-check and constant-fold before code generation needs a concrete
+    // value.
+    Expr(Expr),
 }
 
 /// Object type declaration for enhanced object system
@@ -161,6 +219,11 @@ pub enum ObjectTypeDecl {
 pub struct GrammarDecl {
     pub verbs: Vec<VerbDecl>,
     pub vocabulary: Option<VocabularyDecl>, // Optional vocabulary definitions
+    /// Word-separator characters for the dictionary header (`separators: [",", "."]`),
+    /// e.g. so the runtime tokenizer splits input on punctuation in addition to
+    /// whitespace. `None` means the codegen default applies.
+    pub separators: Option<Vec<char>>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +239,7 @@ pub struct VocabularyDecl {
 pub struct VerbDecl {
     pub word: String,
     pub patterns: Vec<VerbPattern>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -217,6 +281,7 @@ pub struct FunctionDecl {
     pub parameters: Vec<Parameter>,
     pub return_type: Option<Type>,
     pub body: BlockStmt,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -240,12 +305,14 @@ pub enum Type {
 #[derive(Debug, Clone)]
 pub struct InitDecl {
     pub body: BlockStmt,
+    pub span: Span,
 }
 
 // Statements
 #[derive(Debug, Clone)]
 pub struct BlockStmt {
     pub statements: Vec<Stmt>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -266,12 +333,14 @@ pub struct VarDeclStmt {
     pub mutable: bool,
     pub var_type: Option<Type>,
     pub initializer: Option<Expr>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
 pub struct AssignmentStmt {
     pub target: Expr, // Usually an identifier or property access
     pub value: Expr,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -279,12 +348,14 @@ pub struct IfStmt {
     pub condition: Expr,
     pub then_branch: Box<Stmt>,
     pub else_branch: Option<Box<Stmt>>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
 pub struct WhileStmt {
     pub condition: Expr,
     pub body: Box<Stmt>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -292,6 +363,7 @@ pub struct ForStmt {
     pub variable: String,
     pub iterable: Expr,
     pub body: Box<Stmt>,
+    pub span: Span,
 }
 
 // Expressions
@@ -335,6 +407,7 @@ pub enum Expr {
     FunctionCall {
         name: String,
         arguments: Vec<Expr>,
+        span: Span,
     },
     // Method calls: object.property()
     MethodCall {
@@ -348,12 +421,14 @@ pub enum Expr {
         left: Box<Expr>,
         operator: BinaryOp,
         right: Box<Expr>,
+        span: Span,
     },
 
     // Unary operations
     Unary {
         operator: UnaryOp,
         operand: Box<Expr>,
+        span: Span,
     },
 
     // Array literal
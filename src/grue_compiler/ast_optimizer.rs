@@ -0,0 +1,358 @@
+// AST-to-AST optimization pass for Grue language
+//
+// Runs after semantic analysis and before IR generation (see
+// `GrueCompiler::compile_with_options`). Pure syntactic optimization over
+// constant operands - no symbol table, no type information - so every fold
+// below only fires when it can prove (by pattern-matching on already-literal
+// operands) that the expression has no side effects to preserve.
+
+use crate::grue_compiler::ast::*;
+
+/// How aggressively `optimize` simplifies the AST before code generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptLevel {
+    /// Leave the AST exactly as parsed.
+    #[default]
+    None,
+    /// Constant-fold literal arithmetic/logic and drop dead branches.
+    Basic,
+}
+
+/// Fold constant expressions and eliminate dead branches throughout
+/// `program`. A no-op when `level` is `OptLevel::None`.
+pub fn optimize(program: Program, level: OptLevel) -> Program {
+    if level == OptLevel::None {
+        return program;
+    }
+
+    Program {
+        items: program.items.into_iter().map(optimize_item).collect(),
+    }
+}
+
+fn optimize_item(item: Item) -> Item {
+    match item {
+        Item::World(mut world) => {
+            world.rooms = world.rooms.into_iter().map(optimize_room).collect();
+            Item::World(world)
+        }
+        Item::Grammar(mut grammar) => {
+            grammar.verbs = grammar.verbs.into_iter().map(optimize_verb).collect();
+            Item::Grammar(grammar)
+        }
+        Item::Function(mut func) => {
+            func.body = optimize_block(func.body);
+            Item::Function(func)
+        }
+        Item::Init(mut init) => {
+            init.body = optimize_block(init.body);
+            Item::Init(init)
+        }
+        Item::Mode(mode) => Item::Mode(mode),
+    }
+}
+
+fn optimize_room(mut room: RoomDecl) -> RoomDecl {
+    room.objects = room.objects.into_iter().map(optimize_object).collect();
+    room.on_enter = room.on_enter.map(optimize_block);
+    room.on_exit = room.on_exit.map(optimize_block);
+    room.on_look = room.on_look.map(optimize_block);
+    room
+}
+
+fn optimize_object(mut object: ObjectDecl) -> ObjectDecl {
+    object.description = optimize_expr(object.description);
+    object.properties = object
+        .properties
+        .into_iter()
+        .map(|(name, value)| (name, optimize_property_value(value)))
+        .collect();
+    object.numbered_properties = object
+        .numbered_properties
+        .into_iter()
+        .map(|(num, value)| (num, optimize_property_value(value)))
+        .collect();
+    object.contains = object.contains.into_iter().map(optimize_object).collect();
+    object
+}
+
+/// Fold a property value's underlying expression, collapsing a
+/// `PropertyValue::Expr` back into a concrete variant when it turns out to be
+/// a literal after folding (e.g. `weight: 3 * 2` becomes an integer).
+fn optimize_property_value(value: PropertyValue) -> PropertyValue {
+    match value {
+        PropertyValue::Array(elements) => {
+            PropertyValue::Array(elements.into_iter().map(optimize_property_value).collect())
+        }
+        PropertyValue::Expr(expr) => match optimize_expr(expr) {
+            Expr::Boolean(val) => PropertyValue::Boolean(val),
+            Expr::Integer(val) => PropertyValue::Integer(val),
+            Expr::String(val) => PropertyValue::String(val),
+            other => PropertyValue::Expr(other),
+        },
+        literal @ (PropertyValue::Boolean(_)
+        | PropertyValue::Integer(_)
+        | PropertyValue::String(_)
+        | PropertyValue::Byte(_)
+        | PropertyValue::Bytes(_)
+        | PropertyValue::Object(_)
+        | PropertyValue::Room(_)) => literal,
+    }
+}
+
+fn optimize_verb(mut verb: VerbDecl) -> VerbDecl {
+    verb.patterns = verb.patterns.into_iter().map(optimize_pattern).collect();
+    verb
+}
+
+fn optimize_pattern(mut pattern: VerbPattern) -> VerbPattern {
+    pattern.handler = match pattern.handler {
+        Handler::FunctionCall(name, arguments) => {
+            Handler::FunctionCall(name, arguments.into_iter().map(optimize_expr).collect())
+        }
+        Handler::Block(block) => Handler::Block(optimize_block(block)),
+    };
+    pattern
+}
+
+fn optimize_block(mut block: BlockStmt) -> BlockStmt {
+    block.statements = block
+        .statements
+        .into_iter()
+        .filter_map(optimize_stmt)
+        .collect();
+    block
+}
+
+/// Optimize a single statement, returning `None` when the statement can be
+/// dropped entirely (currently only a `while` loop whose condition folds to
+/// `false`, since it's guaranteed to never execute).
+fn optimize_stmt(stmt: Stmt) -> Option<Stmt> {
+    match stmt {
+        Stmt::Expression(expr) => Some(Stmt::Expression(optimize_expr(expr))),
+        Stmt::VarDecl(mut var_decl) => {
+            var_decl.initializer = var_decl.initializer.map(optimize_expr);
+            Some(Stmt::VarDecl(var_decl))
+        }
+        Stmt::Assignment(mut assignment) => {
+            assignment.target = optimize_expr(assignment.target);
+            assignment.value = optimize_expr(assignment.value);
+            Some(Stmt::Assignment(assignment))
+        }
+        Stmt::If(if_stmt) => Some(optimize_if(if_stmt)),
+        Stmt::While(while_stmt) => optimize_while(while_stmt),
+        Stmt::For(for_stmt) => Some(optimize_for(for_stmt)),
+        Stmt::Return(value) => Some(Stmt::Return(value.map(optimize_expr))),
+        Stmt::Block(block) => Some(Stmt::Block(optimize_block(block))),
+    }
+}
+
+/// Replace an `if` whose condition folds to a literal with its taken branch
+/// (or an empty block, reusing the original span, when there's no branch to
+/// take); otherwise keep the `if` but with both branches optimized.
+fn optimize_if(if_stmt: IfStmt) -> Stmt {
+    let IfStmt {
+        condition,
+        then_branch,
+        else_branch,
+        span,
+    } = if_stmt;
+    let condition = optimize_expr(condition);
+    let empty_block = || {
+        Stmt::Block(BlockStmt {
+            statements: vec![],
+            span,
+        })
+    };
+
+    match condition {
+        Expr::Boolean(true) => optimize_stmt(*then_branch).unwrap_or_else(empty_block),
+        Expr::Boolean(false) => else_branch
+            .and_then(|branch| optimize_stmt(*branch))
+            .unwrap_or_else(empty_block),
+        condition => Stmt::If(IfStmt {
+            condition,
+            then_branch: Box::new(optimize_stmt(*then_branch).unwrap_or_else(empty_block)),
+            else_branch: else_branch
+                .and_then(|branch| optimize_stmt(*branch))
+                .map(Box::new),
+            span,
+        }),
+    }
+}
+
+/// Drop the loop entirely when its condition folds to `false` (it can never
+/// execute); otherwise keep it with both condition and body optimized.
+fn optimize_while(while_stmt: WhileStmt) -> Option<Stmt> {
+    let WhileStmt {
+        condition,
+        body,
+        span,
+    } = while_stmt;
+    let condition = optimize_expr(condition);
+
+    if matches!(condition, Expr::Boolean(false)) {
+        return None;
+    }
+
+    let body = optimize_stmt(*body).unwrap_or_else(|| {
+        Stmt::Block(BlockStmt {
+            statements: vec![],
+            span,
+        })
+    });
+    Some(Stmt::While(WhileStmt {
+        condition,
+        body: Box::new(body),
+        span,
+    }))
+}
+
+fn optimize_for(for_stmt: ForStmt) -> Stmt {
+    let ForStmt {
+        variable,
+        iterable,
+        body,
+        span,
+    } = for_stmt;
+    let iterable = optimize_expr(iterable);
+    let body = optimize_stmt(*body).unwrap_or_else(|| {
+        Stmt::Block(BlockStmt {
+            statements: vec![],
+            span,
+        })
+    });
+    Stmt::For(ForStmt {
+        variable,
+        iterable,
+        body: Box::new(body),
+        span,
+    })
+}
+
+fn optimize_expr(expr: Expr) -> Expr {
+    match expr {
+        Expr::Binary {
+            left,
+            operator,
+            right,
+            span,
+        } => fold_binary(operator, optimize_expr(*left), optimize_expr(*right), span),
+        Expr::Unary {
+            operator,
+            operand,
+            span,
+        } => fold_unary(operator, optimize_expr(*operand), span),
+        Expr::Ternary {
+            condition,
+            true_expr,
+            false_expr,
+        } => {
+            let condition = optimize_expr(*condition);
+            let true_expr = optimize_expr(*true_expr);
+            let false_expr = optimize_expr(*false_expr);
+            match condition {
+                Expr::Boolean(true) => true_expr,
+                Expr::Boolean(false) => false_expr,
+                condition => Expr::Ternary {
+                    condition: Box::new(condition),
+                    true_expr: Box::new(true_expr),
+                    false_expr: Box::new(false_expr),
+                },
+            }
+        }
+        Expr::PropertyAccess { object, property } => Expr::PropertyAccess {
+            object: Box::new(optimize_expr(*object)),
+            property,
+        },
+        Expr::NullSafePropertyAccess { object, property } => Expr::NullSafePropertyAccess {
+            object: Box::new(optimize_expr(*object)),
+            property,
+        },
+        Expr::FunctionCall {
+            name,
+            arguments,
+            span,
+        } => Expr::FunctionCall {
+            name,
+            arguments: arguments.into_iter().map(optimize_expr).collect(),
+            span,
+        },
+        Expr::MethodCall {
+            object,
+            method,
+            arguments,
+        } => Expr::MethodCall {
+            object: Box::new(optimize_expr(*object)),
+            method,
+            arguments: arguments.into_iter().map(optimize_expr).collect(),
+        },
+        Expr::Array(elements) => Expr::Array(elements.into_iter().map(optimize_expr).collect()),
+        Expr::MultipleObjects(elements) => {
+            Expr::MultipleObjects(elements.into_iter().map(optimize_expr).collect())
+        }
+        Expr::DisambiguationContext { candidates, query } => Expr::DisambiguationContext {
+            candidates: candidates.into_iter().map(optimize_expr).collect(),
+            query,
+        },
+        literal @ (Expr::Boolean(_)
+        | Expr::Integer(_)
+        | Expr::String(_)
+        | Expr::Identifier(_)
+        | Expr::Parameter(_)
+        | Expr::ParsedObject { .. }) => literal,
+    }
+}
+
+/// Fold `left operator right` when both sides are already literals; anything
+/// else (an identifier, a call, a property access, ...) is left as a
+/// `Binary` node so its side effects and evaluation order are preserved.
+fn fold_binary(operator: BinaryOp, left: Expr, right: Expr, span: Span) -> Expr {
+    match (operator, &left, &right) {
+        (BinaryOp::Add, Expr::Integer(a), Expr::Integer(b)) => Expr::Integer(a.wrapping_add(*b)),
+        (BinaryOp::Subtract, Expr::Integer(a), Expr::Integer(b)) => {
+            Expr::Integer(a.wrapping_sub(*b))
+        }
+        (BinaryOp::Multiply, Expr::Integer(a), Expr::Integer(b)) => {
+            Expr::Integer(a.wrapping_mul(*b))
+        }
+        (BinaryOp::Divide, Expr::Integer(a), Expr::Integer(b)) if *b != 0 => {
+            Expr::Integer(a.wrapping_div(*b))
+        }
+        (BinaryOp::Modulo, Expr::Integer(a), Expr::Integer(b)) if *b != 0 => {
+            Expr::Integer(a.wrapping_rem(*b))
+        }
+        (BinaryOp::Equal, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a == b),
+        (BinaryOp::NotEqual, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a != b),
+        (BinaryOp::Less, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a < b),
+        (BinaryOp::LessEqual, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a <= b),
+        (BinaryOp::Greater, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a > b),
+        (BinaryOp::GreaterEqual, Expr::Integer(a), Expr::Integer(b)) => Expr::Boolean(a >= b),
+        (BinaryOp::Equal, Expr::Boolean(a), Expr::Boolean(b)) => Expr::Boolean(a == b),
+        (BinaryOp::NotEqual, Expr::Boolean(a), Expr::Boolean(b)) => Expr::Boolean(a != b),
+        (BinaryOp::And, Expr::Boolean(a), Expr::Boolean(b)) => Expr::Boolean(*a && *b),
+        (BinaryOp::Or, Expr::Boolean(a), Expr::Boolean(b)) => Expr::Boolean(*a || *b),
+        _ => Expr::Binary {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+            span,
+        },
+    }
+}
+
+fn fold_unary(operator: UnaryOp, operand: Expr, span: Span) -> Expr {
+    match (operator, &operand) {
+        (UnaryOp::Not, Expr::Boolean(b)) => Expr::Boolean(!b),
+        (UnaryOp::Minus, Expr::Integer(n)) => Expr::Integer(n.wrapping_neg()),
+        _ => Expr::Unary {
+            operator,
+            operand: Box::new(operand),
+            span,
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "ast_optimizer_tests.rs"]
+mod tests;
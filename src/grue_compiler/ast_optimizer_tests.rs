@@ -0,0 +1,163 @@
+// Tests for the constant-folding / dead-branch AST optimizer
+
+#[cfg(test)]
+mod ast_optimizer_tests {
+    use crate::grue_compiler::ast::*;
+    use crate::grue_compiler::ast_optimizer::{optimize, OptLevel};
+    use crate::grue_compiler::lexer::Lexer;
+    use crate::grue_compiler::parser::Parser;
+
+    fn optimized_program(input: &str) -> Program {
+        let mut lexer = Lexer::new(input);
+        let tokens = lexer.tokenize().expect("lexing should succeed");
+        let mut parser = Parser::new(tokens, input);
+        let ast = parser.parse().expect("parsing should succeed");
+        optimize(ast, OptLevel::Basic)
+    }
+
+    fn main_function(program: &Program) -> &FunctionDecl {
+        program
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Function(func) if func.name == "main" => Some(func),
+                _ => None,
+            })
+            .expect("expected a main() function")
+    }
+
+    #[test]
+    fn test_opt_level_none_leaves_ast_unchanged() {
+        let input = r#"
+            fn main() {
+                let x = 2 + 3;
+            }
+        "#;
+        let mut lexer = Lexer::new(input);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens, input);
+        let ast = parser.parse().unwrap();
+        let program = optimize(ast, OptLevel::None);
+
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::VarDecl(var_decl) => {
+                assert!(matches!(var_decl.initializer, Some(Expr::Binary { .. })));
+            }
+            other => panic!("expected a var decl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_folds_constant_arithmetic() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                let x = (2 + 3) * 4;
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::VarDecl(var_decl) => {
+                assert!(matches!(var_decl.initializer, Some(Expr::Integer(20))));
+            }
+            other => panic!("expected a var decl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_folds_constant_unary_not() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                let x = !false;
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::VarDecl(var_decl) => {
+                assert!(matches!(var_decl.initializer, Some(Expr::Boolean(true))));
+            }
+            other => panic!("expected a var decl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_does_not_fold_call_containing_expression() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                let x = 2 + random(3);
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::VarDecl(var_decl) => {
+                assert!(matches!(var_decl.initializer, Some(Expr::Binary { .. })));
+            }
+            other => panic!("expected a var decl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_if_with_true_condition_takes_then_branch() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                if (true) {
+                    print("taken");
+                } else {
+                    print("dropped");
+                }
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::Block(block) => {
+                assert_eq!(block.statements.len(), 1);
+            }
+            other => panic!("expected the then-branch block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_if_with_false_condition_and_no_else_becomes_empty() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                if (false) {
+                    print("dropped");
+                }
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        match &func.body.statements[0] {
+            Stmt::Block(block) => {
+                assert!(block.statements.is_empty());
+            }
+            other => panic!("expected an empty block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_while_false_is_eliminated() {
+        let program = optimized_program(
+            r#"
+            fn main() {
+                while (false) {
+                    print("never");
+                }
+                print("after");
+            }
+        "#,
+        );
+        let func = main_function(&program);
+        assert_eq!(func.body.statements.len(), 1);
+        assert!(matches!(func.body.statements[0], Stmt::Expression(_)));
+    }
+}
@@ -157,6 +157,30 @@ pub enum StringPart {
     RuntimeValue(IrId), // IR ID of the runtime value
 }
 
+/// Interning index over the collected string table: a map from string value to its
+/// assigned ID, plus the reverse mapping back to the value, so repeated lookups by
+/// either direction don't have to scan the whole `strings` Vec.
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    by_value: IndexMap<String, IrId>,
+    by_id: IndexMap<IrId, String>,
+}
+
+impl StringInterner {
+    pub(crate) fn record(&mut self, id: IrId, s: &str) {
+        self.by_value.entry(s.to_string()).or_insert(id);
+        self.by_id.insert(id, s.to_string());
+    }
+
+    pub(crate) fn id_for(&self, s: &str) -> Option<IrId> {
+        self.by_value.get(s).copied()
+    }
+
+    pub(crate) fn value_for(&self, id: IrId) -> Option<&String> {
+        self.by_id.get(&id)
+    }
+}
+
 /// Code generation context
 pub struct ZMachineCodeGen {
     pub version: ZMachineVersion,
@@ -254,9 +278,19 @@ pub struct ZMachineCodeGen {
     pub global_vars_addr: usize,
 
     // String encoding
-    pub strings: Vec<(IrId, String)>, // Collected strings for encoding
+    pub strings: Vec<(IrId, String)>, // Collected strings for encoding, in first-seen order
+    /// Hash index over `strings`, giving O(1) value->id and id->value lookups for
+    /// `find_or_create_string_id`/`get_string_value` instead of the O(n) linear scans
+    /// those used to do as `strings` grew.
+    pub string_interner: StringInterner,
     pub main_loop_prompt_id: Option<IrId>, // ID of the main loop prompt string (public for codegen_extensions.rs)
     pub main_loop_unknown_command_id: Option<IrId>, // ID of the "I don't understand" string (public for codegen_extensions.rs)
+    /// Selected abbreviation phrases, in abbreviation-table order (index = abbreviation
+    /// number). Populated by `select_and_register_abbreviations` before string encoding.
+    pub abbreviations: Vec<String>,
+    /// String IDs assigned to each entry of `abbreviations`, parallel to it, so the
+    /// final word-address of each can be looked up in `string_offsets` once known.
+    pub abbreviation_ids: Vec<IrId>,
 
     // Stack tracking for debugging
     pub stack_depth: i32,     // Current estimated stack depth
@@ -320,6 +354,20 @@ pub struct ZMachineCodeGen {
     pub abbreviations_space: Vec<u8>,
     abbreviations_address: usize,
 
+    /// Unicode characters (above U+00FF) encountered while encoding strings, in
+    /// first-seen order, mapped to the ZSCII code (155-251) assigned to each. Populated
+    /// by `collect_unicode_characters` before `encode_all_strings` runs; consulted by
+    /// `char_to_zchars` when emitting the A2 escape sequence for these characters.
+    pub unicode_chars: IndexMap<char, u8>,
+    /// Header extension table + Unicode translation table (Z-Machine Standard 1.1,
+    /// Section 3.8.5.4/11.1.7), built by `generate_unicode_table_space` once
+    /// `unicode_chars` is final. Empty (and the header extension pointer left at 0)
+    /// when no string uses a character above U+00FF.
+    pub unicode_table_space: Vec<u8>,
+    /// Final byte address of `unicode_table_space` (the header extension table itself,
+    /// not the Unicode table it points to), written into header bytes 0x36-0x37.
+    pub final_unicode_ext_base: usize,
+
     /// Code-space label tracking (for immediate jump/branch resolution)
     pub code_labels: IndexMap<IrId, usize>,
 
@@ -345,6 +393,16 @@ pub struct ZMachineCodeGen {
     /// Dictionary words in alphabetically sorted order (for word position lookup)
     /// Populated during generate_dictionary_space(), used by lookup_word_in_dictionary()
     pub dictionary_words: Vec<String>,
+    /// Word-separator characters written into the dictionary header (Z-Machine Standard
+    /// 1.1, Section 13.2), so the runtime tokenizer splits input on punctuation like `,`
+    /// and `.` in addition to whitespace. Defaults to `[',', '.', '"']`; a source-level
+    /// `grammar { separators: [...] }` declaration overrides it (see
+    /// `IrProgram::word_separators`, applied in `mod.rs`'s `compile_with_options`).
+    pub word_separators: Vec<char>,
+    /// DWARF-inspired debug-info sidecar (see `codegen_debug`), collecting final string
+    /// and routine addresses alongside their source symbol. Disabled unless
+    /// `enable_debug_info` is called (wired to the compiler's `--debug-info` flag).
+    pub debug_info: crate::grue_compiler::codegen_debug::DebugInfo,
     /// Set of IR IDs that should use push/pull sequence for stack discipline
     /// Phase C1.1: Track values that need actual VAR:232/233 push/pull opcodes
     pub push_pull_ir_ids: IndexSet<IrId>,
@@ -416,8 +474,11 @@ impl ZMachineCodeGen {
             dictionary_addr: 0,
             global_vars_addr: 0,
             strings: Vec::new(),
+            string_interner: StringInterner::default(),
             main_loop_prompt_id: None,
             main_loop_unknown_command_id: None,
+            abbreviations: Vec::new(),
+            abbreviation_ids: Vec::new(),
             encoded_strings: IndexMap::new(),
             next_string_id: 1000, // Start string IDs from 1000 to avoid conflicts
             stack_depth: 0,
@@ -446,6 +507,9 @@ impl ZMachineCodeGen {
             array_codegen: crate::grue_compiler::codegen_arrays::ArrayCodeGen::new(),
             abbreviations_space: Vec::new(),
             abbreviations_address: 0,
+            unicode_chars: IndexMap::new(),
+            unicode_table_space: Vec::new(),
+            final_unicode_ext_base: 0,
             code_labels: IndexMap::new(),
             string_offsets: IndexMap::new(),
             object_offsets: IndexMap::new(),
@@ -457,6 +521,8 @@ impl ZMachineCodeGen {
             final_abbreviations_base: 0,
             main_program_offset: 0,
             dictionary_words: Vec::new(),
+            word_separators: vec![',', '.', '"'],
+            debug_info: crate::grue_compiler::codegen_debug::DebugInfo::new(),
             push_pull_ir_ids: IndexSet::new(),
             pulled_ir_id_to_global: IndexMap::new(),
             function_call_results: IndexSet::new(),
@@ -6743,7 +6809,8 @@ impl ZMachineCodeGen {
     }
 
     /// Pack a routine address according to Z-Machine version
-    fn pack_routine_address(&self, byte_address: usize) -> Result<u16, CompilerError> {
+    /// Made pub(crate) for use by codegen_debug.rs
+    pub(crate) fn pack_routine_address(&self, byte_address: usize) -> Result<u16, CompilerError> {
         match self.version {
             ZMachineVersion::V3 => {
                 // v3: packed address = byte address / 2
@@ -633,8 +633,8 @@ impl ZMachineCodeGen {
             self.ir_id_to_string.insert(string_id, print_string.clone());
 
             // Ensure the string gets into the encoding system
-            if !self.strings.iter().any(|(id, _)| *id == string_id) {
-                self.strings.push((string_id, print_string.clone()));
+            if self.string_interner.value_for(string_id).is_none() {
+                self.record_string(string_id, &print_string);
                 let encoded = self.encode_string(&print_string)?;
                 self.encoded_strings.insert(string_id, encoded);
                 log::debug!(
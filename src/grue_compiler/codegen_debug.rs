@@ -0,0 +1,222 @@
+/// codegen_debug.rs
+/// DWARF-inspired debug-info sidecar: maps final Z-Machine addresses back to the
+/// Grue symbols (strings, routines) they were generated from.
+///
+/// The IR does not yet carry source spans (line/column) anywhere upstream of codegen,
+/// so the best source-identifying information available today is the symbol itself:
+/// the original string literal text, or the declared routine name. If IR source spans
+/// are added later, `DebugSymbol` can grow a `span` field without changing the sidecar
+/// format established here.
+use crate::grue_compiler::codegen::ZMachineCodeGen;
+use crate::grue_compiler::error::CompilerError;
+use crate::grue_compiler::ir::{IrId, IrProgram};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+
+use log::debug;
+
+/// What a [`DebugSymbol`] identifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugSymbolKind {
+    String,
+    Routine,
+    Object,
+    Room,
+}
+
+impl fmt::Display for DebugSymbolKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DebugSymbolKind::String => write!(f, "string"),
+            DebugSymbolKind::Routine => write!(f, "routine"),
+            DebugSymbolKind::Object => write!(f, "object"),
+            DebugSymbolKind::Room => write!(f, "room"),
+        }
+    }
+}
+
+/// One entry in the debug-info sidecar: a final byte address (and packed address,
+/// where the Z-Machine uses one) tied back to the symbol it was generated from.
+#[derive(Debug, Clone)]
+pub struct DebugSymbol {
+    pub address: u32,
+    pub packed_address: Option<u16>,
+    pub kind: DebugSymbolKind,
+    pub name: String,
+}
+
+/// Collects [`DebugSymbol`] entries during code generation and serializes them to a
+/// `.dbg` sidecar: an address-sorted table a debugger can binary-search by PC or
+/// string address to find the enclosing source symbol, borrowing the shape of
+/// gimli's DWARF line program without requiring a DWARF consumer.
+///
+/// Disabled by default; enabled via `ZMachineCodeGen::enable_debug_info`, which the
+/// compiler CLI wires to `--debug-info`.
+#[derive(Debug, Clone, Default)]
+pub struct DebugInfo {
+    enabled: bool,
+    symbols: Vec<DebugSymbol>,
+}
+
+impl DebugInfo {
+    pub fn new() -> Self {
+        DebugInfo::default()
+    }
+
+    pub fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record(&mut self, symbol: DebugSymbol) {
+        self.symbols.push(symbol);
+    }
+
+    /// Sort entries by final address so a debugger can binary-search the table by PC.
+    pub fn finalize(&mut self) {
+        self.symbols.sort_by_key(|s| s.address);
+    }
+
+    /// Serialize the sorted table to `path` as a plain-text `.dbg` sidecar: one line
+    /// per symbol, `address packed_address kind name`.
+    pub fn write_sidecar(&self, path: &str) -> Result<(), CompilerError> {
+        let mut contents = String::from("# address   packed    kind     name\n");
+        for symbol in &self.symbols {
+            let packed = symbol
+                .packed_address
+                .map(|p| format!("0x{:04x}", p))
+                .unwrap_or_else(|| "-".to_string());
+            contents.push_str(&format!(
+                "0x{:06x} {:>8} {:<8} {}\n",
+                symbol.address, packed, symbol.kind, symbol.name
+            ));
+        }
+        fs::write(path, contents).map_err(|e| {
+            CompilerError::CodeGenError(format!(
+                "Failed to write debug-info sidecar '{}': {}",
+                path, e
+            ))
+        })
+    }
+}
+
+impl ZMachineCodeGen {
+    /// Enable `.dbg` sidecar collection (wired to the `--debug-info` CLI flag).
+    pub fn enable_debug_info(&mut self) {
+        self.debug_info.enable();
+    }
+
+    /// Populate the debug-info table from final string and routine addresses.
+    ///
+    /// Called once in Phase 3, after `assemble_complete_zmachine_image` has converted
+    /// `function_addresses` to absolute addresses and `final_string_base` is known.
+    /// A no-op unless `enable_debug_info` was called first.
+    pub fn populate_debug_info(&mut self, ir: &IrProgram) -> Result<(), CompilerError> {
+        if !self.debug_info.is_enabled() {
+            return Ok(());
+        }
+
+        let function_names: HashMap<IrId, &str> = ir
+            .functions
+            .iter()
+            .map(|f| (f.id, f.name.as_str()))
+            .collect();
+        let string_literals: HashMap<IrId, &str> = ir
+            .string_table
+            .iter()
+            .map(|(text, &id)| (id, text.as_str()))
+            .collect();
+
+        for (&func_id, &address) in &self.function_addresses {
+            let name = function_names
+                .get(&func_id)
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| format!("routine#{}", func_id));
+            let packed_address = self.pack_routine_address(address).ok();
+            self.debug_info.record(DebugSymbol {
+                address: address as u32,
+                packed_address,
+                kind: DebugSymbolKind::Routine,
+                name,
+            });
+        }
+
+        for (&string_id, &offset) in &self.string_offsets {
+            let address = self.final_string_base + offset;
+            let name = string_literals
+                .get(&string_id)
+                .map(|s| Self::debug_label_for_string(s))
+                .unwrap_or_else(|| format!("string#{}", string_id));
+            let packed_address = self.pack_string_address(address).ok();
+            self.debug_info.record(DebugSymbol {
+                address: address as u32,
+                packed_address,
+                kind: DebugSymbolKind::String,
+                name,
+            });
+        }
+
+        let room_names: HashMap<IrId, &str> = ir
+            .rooms
+            .iter()
+            .map(|r| (r.id, r.display_name.as_str()))
+            .collect();
+        let object_names: HashMap<IrId, &str> = ir
+            .objects
+            .iter()
+            .map(|o| (o.id, o.short_name.as_str()))
+            .collect();
+
+        for (&object_id, &offset) in &self.object_offsets {
+            let address = self.final_object_base + offset;
+            let (kind, name) = if let Some(&name) = room_names.get(&object_id) {
+                (DebugSymbolKind::Room, name.to_string())
+            } else if let Some(&name) = object_names.get(&object_id) {
+                (DebugSymbolKind::Object, name.to_string())
+            } else {
+                (DebugSymbolKind::Object, format!("object#{}", object_id))
+            };
+            self.debug_info.record(DebugSymbol {
+                address: address as u32,
+                packed_address: None,
+                kind,
+                name,
+            });
+        }
+
+        self.debug_info.finalize();
+        debug!(
+            "🪲 DEBUG_INFO: Collected {} symbols ({} routines, {} strings, {} objects/rooms)",
+            self.function_addresses.len() + self.string_offsets.len() + self.object_offsets.len(),
+            self.function_addresses.len(),
+            self.string_offsets.len(),
+            self.object_offsets.len()
+        );
+
+        Ok(())
+    }
+
+    /// Trim a string literal down to a short, single-line debug label.
+    fn debug_label_for_string(text: &str) -> String {
+        let collapsed: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if collapsed.chars().count() > 40 {
+            let truncated: String = collapsed.chars().take(37).collect();
+            format!("\"{}...\"", truncated)
+        } else {
+            format!("\"{}\"", collapsed)
+        }
+    }
+
+    /// Write the populated debug-info table to `path` as a `.dbg` sidecar.
+    /// No-op unless `enable_debug_info` was called first.
+    pub fn write_debug_info(&self, path: &str) -> Result<(), CompilerError> {
+        if !self.debug_info.is_enabled() {
+            return Ok(());
+        }
+        self.debug_info.write_sidecar(path)
+    }
+}
@@ -120,6 +120,23 @@ impl ZMachineCodeGen {
         Ok(())
     }
 
+    /// Write the header-extension table pointer (bytes 0x36-0x37) when a Unicode
+    /// translation table was generated. No-op when the game used no extended characters.
+    pub fn write_unicode_header_extension(&mut self) -> Result<(), CompilerError> {
+        if self.unicode_table_space.is_empty() {
+            return Ok(());
+        }
+
+        let addr = self.final_unicode_ext_base as u16;
+        let header = &mut self.final_data[0..HEADER_SIZE];
+        header[54] = (addr >> 8) as u8;
+        header[55] = (addr & 0xFF) as u8;
+
+        log::debug!("📝 Header extension table pointer: 0x{:04x}", addr);
+
+        Ok(())
+    }
+
     /// Finalize header metadata (checksums, high/low water marks)  
     pub fn finalize_header_metadata(&mut self) -> Result<(), CompilerError> {
         log::debug!("📝 Phase 3: Finalizing header metadata");
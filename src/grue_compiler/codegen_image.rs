@@ -3,6 +3,7 @@
 ///
 use crate::grue_compiler::codegen::ZMachineCodeGen;
 use crate::grue_compiler::codegen_memory::{MemorySpace, HEADER_SIZE};
+use crate::grue_compiler::codegen_spaces::UNICODE_HEADER_EXT_SIZE;
 use crate::grue_compiler::codegen_utils::CodeGenUtils;
 use crate::grue_compiler::error::CompilerError;
 use crate::grue_compiler::ir::*;
@@ -52,6 +53,10 @@ impl ZMachineCodeGen {
         let (prompt_id, unknown_command_id) = self.add_main_loop_strings(&ir)?;
         self.main_loop_prompt_id = Some(prompt_id);
         self.main_loop_unknown_command_id = Some(unknown_command_id);
+        // Must run before abbreviation selection/encoding: both phrase scoring and string
+        // encoding need every extended-Unicode character's ZSCII code already assigned.
+        self.collect_unicode_characters()?;
+        self.select_and_register_abbreviations();
         self.encode_all_strings()?;
         log::info!(" Phase 1 complete: Content analysis and string encoding finished");
 
@@ -210,6 +215,15 @@ impl ZMachineCodeGen {
             self.abbreviations_space.len()
         );
 
+        // Phase 2e2: Generate the Unicode translation table (header-extension + code points)
+        log::debug!("🔤 Step 2e2: Generating Unicode translation table space");
+        self.generate_unicode_table_space()?;
+        log::info!(
+            " Step 2e2 complete: Unicode table space populated ({} bytes, {} characters)",
+            self.unicode_table_space.len(),
+            self.unicode_chars.len()
+        );
+
         // Phase 2f: Generate executable code to code_space
         log::debug!("💻 Step 2f: Generating code space");
         self.generate_code_to_space(ir)?;
@@ -300,7 +314,7 @@ impl ZMachineCodeGen {
     ///
     pub fn assemble_complete_zmachine_image(
         &mut self,
-        _ir: &IrProgram,
+        ir: &IrProgram,
     ) -> Result<Vec<u8>, CompilerError> {
         log::info!(" Phase 3: Assembling complete Z-Machine image from ALL separated spaces");
 
@@ -383,6 +397,19 @@ impl ZMachineCodeGen {
         );
         current_address += code_size;
 
+        // Unicode translation table (header-extension table + code points), trailing the
+        // image. Only allocated when the source actually used extended characters.
+        let unicode_ext_size = self.unicode_table_space.len();
+        let unicode_ext_base = current_address;
+        if unicode_ext_size > 0 {
+            log::debug!(
+                " Unicode table allocated at 0x{:04x}, size={} bytes",
+                unicode_ext_base,
+                unicode_ext_size
+            );
+        }
+        current_address += unicode_ext_size;
+
         // Total file size calculation
         let total_size = current_address;
 
@@ -391,6 +418,8 @@ impl ZMachineCodeGen {
         self.final_string_base = string_base;
         self.final_object_base = object_base;
         self.final_abbreviations_base = abbreviations_base;
+        self.final_unicode_ext_base = unicode_ext_base;
+        self.populate_abbreviations_table()?;
         self.dictionary_addr = dictionary_base;
         self.global_vars_addr = globals_base;
 
@@ -483,9 +512,17 @@ impl ZMachineCodeGen {
         log::info!(
             " ├─ Code: 0x{:04x}-0x{:04x} ({} bytes) - Executable functions",
             code_base,
-            total_size,
+            code_base + code_size,
             code_size
         );
+        if unicode_ext_size > 0 {
+            log::info!(
+                " ├─ Unicode table: 0x{:04x}-0x{:04x} ({} bytes) - Header-extension + code points",
+                unicode_ext_base,
+                total_size,
+                unicode_ext_size
+            );
+        }
         log::info!(" └─ Total: {} bytes (Complete Z-Machine file)", total_size);
 
         // PC calculation preview (final calculation happens in Step 3e)
@@ -723,7 +760,7 @@ impl ZMachineCodeGen {
  );
             }
 
-            self.final_data[code_base..total_size].copy_from_slice(&self.code_space);
+            self.final_data[code_base..code_base + code_size].copy_from_slice(&self.code_space);
 
             log::debug!(
                 " Code space copied: {} bytes at 0x{:04x}",
@@ -749,6 +786,25 @@ impl ZMachineCodeGen {
             );
         }
 
+        // Copy Unicode translation table space
+        if !self.unicode_table_space.is_empty() {
+            self.final_data[unicode_ext_base..total_size]
+                .copy_from_slice(&self.unicode_table_space);
+
+            // Patch word3 (Unicode table address) now that the table's final, absolute
+            // address is known; generate_unicode_table_space could only leave a placeholder.
+            let unicode_table_addr = (unicode_ext_base + UNICODE_HEADER_EXT_SIZE) as u16;
+            self.final_data[unicode_ext_base + 6] = (unicode_table_addr >> 8) as u8;
+            self.final_data[unicode_ext_base + 7] = (unicode_table_addr & 0xFF) as u8;
+
+            log::debug!(
+                " Unicode table space copied: {} bytes at 0x{:04x} (table at 0x{:04x})",
+                unicode_ext_size,
+                unicode_ext_base,
+                unicode_table_addr
+            );
+        }
+
         // Phase 3e: Update address fields with final calculated addresses
         // This phase updates ONLY the address fields in the header with final memory layout.
         // Critical: Never touches static fields like serial number or version.
@@ -775,6 +831,7 @@ impl ZMachineCodeGen {
             abbreviations_base as u16,     // abbreviations_addr
             self.final_code_base as u16,   // high_mem_base
         )?;
+        self.write_unicode_header_extension()?;
 
         // Phase 3e.5: Map all object IR IDs to addresses (CRITICAL FIX for UnresolvedReference resolution)
         log::debug!(
@@ -786,6 +843,10 @@ impl ZMachineCodeGen {
         log::debug!(" Step 3e.6: Consolidating ALL IR ID mappings (functions, strings, labels)");
         self.consolidate_all_ir_mappings();
 
+        // Phase 3e.7: Populate the debug-info sidecar (no-op unless --debug-info was passed)
+        log::debug!(" Step 3e.7: Populating debug-info table from final addresses");
+        self.populate_debug_info(ir)?;
+
         // Phase 3f: Resolve all address references (including string properties)
         log::debug!(" Step 3f: Resolving all address references and fixups");
         self.resolve_all_addresses()?;
@@ -3686,6 +3686,7 @@ impl ZMachineCodeGen {
                 left,
                 operator,
                 right,
+                ..
             } => {
                 // Evaluate operands to get Z-Machine operands
                 let left_operand = self.evaluate_expression_to_operand(left)?;
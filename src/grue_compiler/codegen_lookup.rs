@@ -14,6 +14,7 @@ use crate::grue_compiler::codegen_objects::Operand;
 use crate::grue_compiler::codegen_references::{LegacyReferenceType, UnresolvedReference};
 use crate::grue_compiler::error::CompilerError;
 use crate::grue_compiler::opcodes::{Op1, Op2, Opcode};
+use crate::grue_compiler::ZMachineVersion;
 use log::debug;
 
 impl ZMachineCodeGen {
@@ -21,19 +22,24 @@ impl ZMachineCodeGen {
     /// This calculates the dictionary address based on alphabetical position
     pub(crate) fn lookup_word_in_dictionary(&self, word: &str) -> Result<u16, CompilerError> {
         // Dictionary layout:
-        // [0] = separator count (0)
-        // [1] = entry length (6)
-        // [2-3] = entry count (2 bytes, big-endian)
-        // [4+] = entries (6 bytes each, sorted alphabetically)
+        // [0] = separator count (n)
+        // [1..1+n] = separator characters
+        // [1+n] = entry length (6 for v3, 9 for v4/v5)
+        // [2+n..4+n] = entry count (2 bytes, big-endian)
+        // [4+n..] = entries (entry_size bytes each, sorted alphabetically)
 
         // Dictionary starts at dictionary_addr offset
         let dict_base = self.dictionary_addr as u16;
 
-        // Header is 4 bytes (separator count, entry length, entry count)
-        let header_size = 4u16;
+        // Header is 4 bytes (separator count, entry length, entry count) plus one byte
+        // per configured separator character.
+        let header_size = 4u16 + self.word_separators.len() as u16;
 
-        // Entry size is 6 bytes for v3
-        let entry_size = 6u16;
+        // Entry size matches encode_word_to_zchars' version-dependent layout
+        let entry_size: u16 = match self.version {
+            ZMachineVersion::V3 => 6,
+            ZMachineVersion::V4 | ZMachineVersion::V5 => 9,
+        };
 
         // Find the word's position in the sorted dictionary_words list
         let word_lower = word.to_lowercase();
@@ -296,14 +296,18 @@ impl ZMachineCodeGen {
             LegacyReferenceType::DictionaryRef { word } => {
                 // Calculate dictionary address in final layout
                 // Dictionary layout:
-                // [0] = separator count (0)
-                // [1] = entry length (6)
-                // [2-3] = entry count (2 bytes, big-endian)
-                // [4+] = entries (6 bytes each, sorted alphabetically)
+                // [0] = separator count (n)
+                // [1..1+n] = separator characters
+                // [1+n] = entry length (6 for v3, 9 for v4/v5)
+                // [2+n..4+n] = entry count (2 bytes, big-endian)
+                // [4+n..] = entries (entry_size bytes each, sorted alphabetically)
 
                 let dict_base = self.dictionary_addr; // Now has Phase 3 final value
-                let header_size = 4;
-                let entry_size = 6;
+                let header_size = 4 + self.word_separators.len();
+                let entry_size: usize = match self.version {
+                    ZMachineVersion::V3 => 6,
+                    ZMachineVersion::V4 | ZMachineVersion::V5 => 9,
+                };
 
                 // target_id stores the position (from Phase 2)
                 let position = reference.target_id as usize;
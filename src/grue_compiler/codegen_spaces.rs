@@ -6,6 +6,22 @@ use crate::grue_compiler::codegen_utils::CodeGenUtils;
 use crate::grue_compiler::error::CompilerError;
 use crate::grue_compiler::ir::*;
 use crate::grue_compiler::ZMachineVersion;
+use std::collections::HashSet;
+
+/// Z-Machine abbreviation table: 3 banks of 32 entries (96 total), each a 2-byte word
+/// address (Z-Machine Standard 1.1, Section 3.3).
+const MAX_ABBREVIATIONS: usize = 96;
+/// Candidate phrases longer than this stop paying off in practice: the 2-zchar
+/// abbreviation reference itself already costs as much as a ~3-character literal run.
+const MAX_ABBREVIATION_CHARS: usize = 8;
+/// IR IDs for abbreviation strings are allocated from this base, clear of the ranges
+/// `find_or_create_string_id` and `collect_strings` use for ordinary program strings.
+const ABBREVIATION_STRING_ID_BASE: IrId = 10000;
+
+/// Size of the header-extension-table header written by `generate_unicode_table_space`:
+/// one word-count word plus the 3 data words it declares (mouse x, mouse y, Unicode table
+/// address). The Unicode table itself starts right after it.
+pub(crate) const UNICODE_HEADER_EXT_SIZE: usize = 8;
 
 impl ZMachineCodeGen {
     /// Generate global variables space (240 variables * 2 bytes = 480 bytes)
@@ -27,136 +43,230 @@ impl ZMachineCodeGen {
         Ok(())
     }
 
-    /// Generate abbreviations space for string compression
+    /// Reserve the abbreviations table (3 banks of 32 word-address entries). The table
+    /// itself is filled in later, once the abbreviation strings have final addresses —
+    /// see `populate_abbreviations_table`, called during final image assembly.
     pub fn generate_abbreviations_space(&mut self, _ir: &IrProgram) -> Result<(), CompilerError> {
         log::debug!("📚 Generating abbreviations space");
-
-        // Z-Machine abbreviations table has 3 tables of 32 entries each (96 total)
-        // Each entry is a word address (2 bytes), so total is 192 bytes
-        const NUM_ABBREVIATIONS: usize = 96;
         const BYTES_PER_ABBREVIATION: usize = 2;
-        const TOTAL_ABBREVIATIONS_SIZE: usize = NUM_ABBREVIATIONS * BYTES_PER_ABBREVIATION;
-
-        // Analyze strings to identify common patterns for abbreviation
-        let abbreviation_candidates = self.analyze_strings_for_abbreviations();
-
-        // Create abbreviation table
-        self.abbreviations_space.resize(TOTAL_ABBREVIATIONS_SIZE, 0);
+        self.abbreviations_space
+            .resize(MAX_ABBREVIATIONS * BYTES_PER_ABBREVIATION, 0);
+        log::debug!(
+            " Abbreviations space reserved: {} bytes ({} abbreviations selected)",
+            self.abbreviations_space.len(),
+            self.abbreviations.len()
+        );
+        Ok(())
+    }
 
-        // Store common abbreviations as strings to be encoded later
-        // For now, we'll create placeholders that will be filled during final assembly
-        let mut abbreviations_created = 0;
-        for (index, candidate) in abbreviation_candidates
-            .iter()
-            .take(NUM_ABBREVIATIONS)
-            .enumerate()
-        {
-            // Store the abbreviation string for later encoding
-            // Each abbreviation gets a unique ID starting from a high number to avoid conflicts
-            let abbrev_id = 10000 + index as IrId;
-            self.strings.push((abbrev_id, candidate.clone()));
-            log::debug!("📚 Created abbreviation {}: '{}'", index, candidate);
-            abbreviations_created += 1;
+    /// Build the Unicode translation table space: a small header-extension-table header
+    /// (Z-Machine Standard 1.1, Section 11.1.7 — word count, mouse x, mouse y, and the
+    /// address of the Unicode table) immediately followed by the Unicode table itself
+    /// (Section 3.8.5.4 — a count byte plus one big-endian u16 per extended character, in
+    /// ZSCII-code order). A no-op when `collect_unicode_characters` found no extended
+    /// characters to translate.
+    pub fn generate_unicode_table_space(&mut self) -> Result<(), CompilerError> {
+        if self.unicode_chars.is_empty() {
+            self.unicode_table_space.clear();
+            return Ok(());
         }
 
         log::debug!(
-            " Abbreviations space created: {} bytes ({}/{} abbreviations populated)",
-            self.abbreviations_space.len(),
-            abbreviations_created,
-            NUM_ABBREVIATIONS
+            "🔤 Generating Unicode translation table space ({} characters)",
+            self.unicode_chars.len()
         );
+
+        const HEADER_EXT_WORD_COUNT: u16 = 3;
+
+        // Word3 (the Unicode table address) is a final, absolute address, which isn't known
+        // until the image is assembled in Phase 3 — it's patched in by
+        // `assemble_complete_zmachine_image` once `final_unicode_ext_base` is set; 0 here is
+        // just a placeholder.
+        let mut space =
+            Vec::with_capacity(UNICODE_HEADER_EXT_SIZE + 1 + self.unicode_chars.len() * 2);
+
+        space.extend_from_slice(&HEADER_EXT_WORD_COUNT.to_be_bytes());
+        space.extend_from_slice(&0u16.to_be_bytes()); // word1: mouse x (unused)
+        space.extend_from_slice(&0u16.to_be_bytes()); // word2: mouse y (unused)
+        space.extend_from_slice(&0u16.to_be_bytes()); // word3: Unicode table address (patched later)
+
+        space.push(self.unicode_chars.len() as u8);
+        for (&ch, _) in self.unicode_chars.iter() {
+            space.extend_from_slice(&(ch as u32 as u16).to_be_bytes());
+        }
+
+        self.unicode_table_space = space;
         Ok(())
     }
 
-    /// Analyze collected strings to identify the best abbreviation candidates
-    ///
-    /// This function implements intelligent string analysis to find optimal abbreviation
-    /// candidates based on frequency analysis and space savings potential. It examines
-    /// both individual words and short phrases to maximize compression efficiency.
+    /// Select abbreviations from the collected strings and register them as strings to
+    /// encode, so they ride along with the rest of `self.strings` through
+    /// `encode_all_strings`/`allocate_string_space`. Must run after `collect_strings`
+    /// and before `encode_all_strings`.
+    pub fn select_and_register_abbreviations(&mut self) {
+        let selected = self.select_abbreviations();
+
+        for (index, phrase) in selected.iter().enumerate() {
+            let id = ABBREVIATION_STRING_ID_BASE + index as IrId;
+            self.record_string(id, phrase);
+            self.abbreviation_ids.push(id);
+        }
+
+        log::debug!("📚 Selected {} abbreviations", selected.len());
+        self.abbreviations = selected;
+    }
+
+    /// Greedily choose up to [`MAX_ABBREVIATIONS`] abbreviation phrases from the
+    /// collected strings.
     ///
-    /// The Z-Machine abbreviation system allows up to 32 abbreviations (numbered 0-31)
-    /// that can significantly reduce file size by eliminating string duplication.
-    fn analyze_strings_for_abbreviations(&self) -> Vec<String> {
-        use std::collections::HashMap;
-
-        let mut word_counts = HashMap::new();
-        let mut phrase_counts = HashMap::new();
-
-        // Count individual words and short phrases
-        for (_, string) in &self.strings {
-            // Count words
-            for word in string.split_whitespace() {
-                if word.len() >= 3 && word.len() <= 8 {
-                    *word_counts.entry(word.to_string()).or_insert(0) += 1;
+    /// Candidate phrases are whitespace-boundary-aligned n-grams (starting at the
+    /// beginning of a string or right after a space), up to [`MAX_ABBREVIATION_CHARS`]
+    /// long. Each round scores every remaining candidate by
+    /// `(zchars_in_phrase - 2) * occurrences` — an abbreviation reference always costs 2
+    /// Z-chars, so this is the net Z-char savings — and selects the highest scorer. Its
+    /// occurrences are then blanked out of the corpus before the next round looks for
+    /// more candidates, so later rounds never double-count text an earlier round already
+    /// covers, and no abbreviation can end up containing an earlier one.
+    fn select_abbreviations(&self) -> Vec<String> {
+        let mut corpus: Vec<String> = self.strings.iter().map(|(_, s)| s.clone()).collect();
+        let mut selected = Vec::new();
+
+        while selected.len() < MAX_ABBREVIATIONS {
+            let candidates = Self::collect_ngram_candidates(&corpus);
+
+            let mut best: Option<(String, i64)> = None;
+            for phrase in candidates {
+                let occurrences: i64 = corpus
+                    .iter()
+                    .map(|s| s.matches(phrase.as_str()).count() as i64)
+                    .sum();
+                if occurrences < 2 {
+                    continue;
+                }
+                let zchars = self.phrase_zchar_count(&phrase) as i64;
+                let score = (zchars - 2) * occurrences;
+                if score <= 0 {
+                    continue;
+                }
+                let is_better = match &best {
+                    None => true,
+                    Some((best_phrase, best_score)) => {
+                        score > *best_score
+                            || (score == *best_score && phrase.len() > best_phrase.len())
+                            || (score == *best_score
+                                && phrase.len() == best_phrase.len()
+                                && phrase < *best_phrase)
+                    }
+                };
+                if is_better {
+                    best = Some((phrase, score));
                 }
             }
 
-            // Count 2-word phrases
-            let words: Vec<&str> = string.split_whitespace().collect();
-            for window in words.windows(2) {
-                let phrase = format!("{} {}", window[0], window[1]);
-                if phrase.len() >= 4 && phrase.len() <= 12 {
-                    *phrase_counts.entry(phrase).or_insert(0) += 1;
+            match best {
+                None => break,
+                Some((phrase, score)) => {
+                    log::debug!(
+                        "📊 Selected abbreviation #{}: '{}' (score {})",
+                        selected.len(),
+                        phrase,
+                        score
+                    );
+                    let blank = " ".repeat(phrase.chars().count());
+                    for s in corpus.iter_mut() {
+                        *s = s.replace(phrase.as_str(), &blank);
+                    }
+                    selected.push(phrase);
                 }
             }
         }
 
-        // Collect candidates, prioritizing by frequency and savings potential
-        let mut candidates = Vec::new();
-
-        // Add high-frequency words first (minimum 3 occurrences, good savings potential)
-        let mut words: Vec<(String, usize)> = word_counts
-            .into_iter()
-            .filter(|(word, count)| *count >= 3 && word.len() >= 3)
-            .collect();
-        words.sort_by(|(_, a), (_, b)| b.cmp(a)); // Sort by frequency descending
+        selected
+    }
 
-        for (word, count) in words.iter().take(20) {
-            let savings = (word.len() - 1) * count; // Rough savings calculation
-            log::debug!(
-                "📊 Word candidate: '{}' (×{}, ~{} bytes saved)",
-                word,
-                count,
-                savings
+    /// All whitespace-boundary-aligned substrings of `corpus`, 2 to
+    /// [`MAX_ABBREVIATION_CHARS`] characters long.
+    fn collect_ngram_candidates(corpus: &[String]) -> HashSet<String> {
+        let mut candidates = HashSet::new();
+
+        for text in corpus {
+            let chars: Vec<char> = text.chars().collect();
+            let mut starts = vec![0usize];
+            starts.extend(
+                chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &c)| c == ' ')
+                    .map(|(i, _)| i + 1),
             );
-            candidates.push(word.clone());
+
+            for start in starts {
+                if start >= chars.len() {
+                    continue;
+                }
+                let max_len = MAX_ABBREVIATION_CHARS.min(chars.len() - start);
+                for len in 2..=max_len {
+                    let candidate: String = chars[start..start + len].iter().collect();
+                    if candidate.trim().is_empty() {
+                        continue;
+                    }
+                    candidates.insert(candidate);
+                }
+            }
         }
 
-        // Add high-frequency phrases
-        let mut phrases: Vec<(String, usize)> = phrase_counts
-            .into_iter()
-            .filter(|(phrase, count)| *count >= 2 && phrase.len() >= 4)
-            .collect();
-        phrases.sort_by(|(_, a), (_, b)| b.cmp(a)); // Sort by frequency descending
+        candidates
+    }
 
-        for (phrase, count) in phrases.iter().take(10) {
-            let savings = (phrase.len() - 1) * count;
-            log::debug!(
-                "📊 Phrase candidate: '{}' (×{}, ~{} bytes saved)",
-                phrase,
-                count,
-                savings
-            );
-            candidates.push(phrase.clone());
-        }
+    /// Z-char cost of encoding `phrase` with no abbreviation substitution, for scoring
+    /// abbreviation candidates against the 2-zchar cost of referencing one.
+    fn phrase_zchar_count(&self, phrase: &str) -> usize {
+        phrase
+            .chars()
+            .map(|c| self.char_to_zchars(c).map(|v| v.len()).unwrap_or(0))
+            .sum()
+    }
 
-        // Add some common Z-Machine game patterns manually
-        let common_patterns = vec![
-            "You can't".to_string(),
-            "You are".to_string(),
-            "You have".to_string(),
-            "There is".to_string(),
-            "the ".to_string(),
-        ];
-
-        for pattern in common_patterns {
-            if !candidates.contains(&pattern) {
-                candidates.push(pattern);
+    /// Fill in the abbreviation table with the final word-address of each selected
+    /// abbreviation string. Must run after `final_string_base` and `string_offsets` are
+    /// finalized (during final image assembly), and before the abbreviations space is
+    /// copied into the final image.
+    pub fn populate_abbreviations_table(&mut self) -> Result<(), CompilerError> {
+        let entries: Vec<(usize, IrId)> =
+            self.abbreviation_ids.iter().copied().enumerate().collect();
+
+        for (index, string_id) in entries {
+            let offset = self
+                .string_offsets
+                .get(&string_id)
+                .copied()
+                .ok_or_else(|| {
+                    CompilerError::CodeGenError(format!(
+                        "Abbreviation string ID {} not found in string_offsets",
+                        string_id
+                    ))
+                })?;
+            let absolute_addr = self.final_string_base + offset;
+            if absolute_addr % 2 != 0 {
+                return Err(CompilerError::CodeGenError(format!(
+                    "Abbreviation string at 0x{:04x} is not word-aligned",
+                    absolute_addr
+                )));
             }
+            let word_addr = (absolute_addr / 2) as u16;
+            let entry_offset = index * 2;
+            self.abbreviations_space[entry_offset] = (word_addr >> 8) as u8;
+            self.abbreviations_space[entry_offset + 1] = (word_addr & 0xFF) as u8;
+            log::debug!(
+                "📚 Abbreviation #{}: string ID {} at 0x{:04x} -> word address 0x{:04x}",
+                index,
+                string_id,
+                absolute_addr,
+                word_addr
+            );
         }
 
-        log::debug!("📚 Generated {} abbreviation candidates", candidates.len());
-        candidates
+        Ok(())
     }
 
     /// Generate code instructions to code space
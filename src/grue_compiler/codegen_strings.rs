@@ -11,6 +11,34 @@ use log::debug;
 // Re-export common types for string handling
 pub use crate::grue_compiler::codegen::{MemorySpace, ZMachineCodeGen};
 
+/// Dictionary entry part-of-speech flags, packed into the first data byte of each
+/// encoded entry so the runtime parser can classify a word without re-deriving its
+/// grammar role (Infocom convention).
+const DICT_FLAG_VERB: u8 = 0x01;
+const DICT_FLAG_NOUN: u8 = 0x02;
+const DICT_FLAG_PREPOSITION: u8 = 0x04;
+const DICT_FLAG_DIRECTION: u8 = 0x08;
+
+/// First ZSCII code available for characters above U+00FF (Z-Machine Standard 1.1,
+/// Section 3.8.5.4): the Unicode translation table fills ZSCII codes 155-251.
+const UNICODE_ZSCII_BASE: u32 = 155;
+/// 251 - 155 + 1: the Unicode translation table has at most this many entries.
+const MAX_UNICODE_CHARS: usize = 97;
+
+/// Sentinel verb index for words flagged [`DICT_FLAG_VERB`] that aren't defined by an
+/// `ir.grammar` rule (e.g. the built-in `quit` command).
+const DICT_VERB_INDEX_BUILTIN: u8 = 0xFF;
+
+/// Vocabulary classification for a single dictionary word, computed once in
+/// `generate_dictionary_space` from `ir.grammar`/`ir.rooms` and threaded down into
+/// `encode_word_to_zchars` so the encoded entry carries real part-of-speech data
+/// instead of a constant placeholder.
+#[derive(Debug, Clone, Copy, Default)]
+struct DictionaryWordInfo {
+    flags: u8,
+    verb_index: u8,
+}
+
 impl ZMachineCodeGen {
     /// String Collection Functions
 
@@ -21,7 +49,7 @@ impl ZMachineCodeGen {
         string_entries.sort_by_key(|(_, &id)| id); // Sort by string ID for stable allocation order
 
         for (string, &id) in string_entries {
-            self.strings.push((id, string.clone()));
+            self.record_string(id, string);
         }
 
         // Collect strings from LoadImmediate instructions in all functions
@@ -149,7 +177,7 @@ impl ZMachineCodeGen {
                     target,
                     value: IrValue::String(s),
                 } => {
-                    self.strings.push((*target, s.clone()));
+                    self.record_string(*target, s);
                 }
                 _ => {} // Other instructions don't contain strings
             }
@@ -159,6 +187,37 @@ impl ZMachineCodeGen {
 
     /// String Encoding Functions
 
+    /// Scan every collected string for characters above U+00FF and assign each
+    /// distinct one a ZSCII code starting at 155, in first-seen order. Must run after
+    /// `collect_strings`/`add_main_loop_strings` and before `encode_all_strings`, so
+    /// `char_to_zchars` can look up the assigned code for any extended character it
+    /// encounters. Fails only once more than `MAX_UNICODE_CHARS` distinct extended
+    /// characters are used, since the Unicode translation table has no more room.
+    pub fn collect_unicode_characters(&mut self) -> Result<(), CompilerError> {
+        for (_, string) in &self.strings {
+            for ch in string.chars() {
+                if (ch as u32) <= 0xFF || self.unicode_chars.contains_key(&ch) {
+                    continue;
+                }
+                if self.unicode_chars.len() >= MAX_UNICODE_CHARS {
+                    return Err(CompilerError::CodeGenError(format!(
+                        "Too many distinct Unicode characters above U+00FF ({}+); the \
+                         Z-Machine Unicode translation table supports at most {} entries",
+                        self.unicode_chars.len() + 1,
+                        MAX_UNICODE_CHARS
+                    )));
+                }
+                let zscii_code = (UNICODE_ZSCII_BASE + self.unicode_chars.len() as u32) as u8;
+                debug!(
+                    "🌐 UNICODE_CHAR: '{}' (U+{:04X}) -> ZSCII {}",
+                    ch, ch as u32, zscii_code
+                );
+                self.unicode_chars.insert(ch, zscii_code);
+            }
+        }
+        Ok(())
+    }
+
     /// Encode all collected strings using Z-Machine ZSCII encoding
     pub fn encode_all_strings(&mut self) -> Result<(), CompilerError> {
         debug!(
@@ -166,7 +225,13 @@ impl ZMachineCodeGen {
             self.strings.len()
         );
         for (id, string) in &self.strings {
-            let encoded = self.encode_string(string)?;
+            // Abbreviation strings are never themselves allowed to reference other
+            // abbreviations, so they bypass substitution entirely.
+            let encoded = if self.abbreviation_ids.contains(id) {
+                self.encode_string_raw(string)?
+            } else {
+                self.encode_string(string)?
+            };
             debug!(
                 "STRING_ENCODE_DEBUG: ID {} = '{}' → {} bytes: {:02x?}",
                 id,
@@ -183,130 +248,42 @@ impl ZMachineCodeGen {
         Ok(())
     }
 
-    /// Encode a single string using Z-Machine ZSCII encoding
+    /// Encode a single string using Z-Machine ZSCII encoding, substituting any matching
+    /// abbreviation phrases along the way.
     pub fn encode_string(&self, s: &str) -> Result<Vec<u8>, CompilerError> {
-        // Z-Machine text encoding per Z-Machine Standard 1.1, Section 3.5.3
-        // Alphabet A0 (6-31): abcdefghijklmnopqrstuvwxyz
-        // Alphabet A1 (6-31): ABCDEFGHIJKLMNOPQRSTUVWXYZ
-        // Alphabet A2 (6-31):  ^0123456789.,!?_#'"/\-:()
-
-        let mut zchars = Vec::new();
-
-        for ch in s.chars() {
-            match ch {
-                // Space is always Z-character 0
-                ' ' => zchars.push(0),
-
-                // Newline is A2[7] = newline (ZSCII 13)
-                '\n' => {
-                    zchars.push(5); // Single shift to alphabet A2
-                    zchars.push(7); // A2[7] = newline
-                }
-
-                // Alphabet A0: lowercase letters (Z-chars 6-31)
-                'a'..='z' => {
-                    zchars.push(ch as u8 - b'a' + 6);
-                }
-
-                // Alphabet A1: uppercase letters (single-shift with 4, then Z-char 6-31)
-                'A'..='Z' => {
-                    zchars.push(4); // Single shift to alphabet A1
-                    zchars.push(ch as u8 - b'A' + 6);
-                }
-
-                // Alphabet A2: digits and punctuation (single-shift with 5, then Z-char 6-31)
-                '0'..='9' => {
-                    zchars.push(5); // Single shift to alphabet A2
-                    zchars.push(ch as u8 - b'0' + 8); // A2[8-17] = "0123456789"
-                }
-
-                '.' => {
-                    zchars.push(5);
-                    zchars.push(18); // A2[18] = '.'
-                }
-
-                ',' => {
-                    zchars.push(5);
-                    zchars.push(19); // A2[19] = ','
-                }
-
-                '!' => {
-                    zchars.push(5);
-                    zchars.push(20); // A2[20] = '!'
-                }
-
-                '?' => {
-                    zchars.push(5);
-                    zchars.push(21); // A2[21] = '?'
-                }
-
-                '_' => {
-                    zchars.push(5);
-                    zchars.push(22); // A2[22] = '_'
-                }
-
-                '#' => {
-                    zchars.push(5);
-                    zchars.push(23); // A2[23] = '#'
-                }
-
-                '\'' => {
-                    zchars.push(5);
-                    zchars.push(24); // A2[24] = '\''
-                }
-
-                '"' => {
-                    zchars.push(5);
-                    zchars.push(25); // A2[25] = '"'
-                }
-
-                '/' => {
-                    zchars.push(5);
-                    zchars.push(26); // A2[26] = '/'
-                }
-
-                '\\' => {
-                    zchars.push(5);
-                    zchars.push(27); // A2[27] = '\'
-                }
-
-                '-' => {
-                    zchars.push(5);
-                    zchars.push(28); // A2[28] = '-'
-                }
-
-                ':' => {
-                    zchars.push(5);
-                    zchars.push(29); // A2[29] = ':'
-                }
+        self.encode_string_zchars(s, true)
+    }
 
-                '(' => {
-                    zchars.push(5);
-                    zchars.push(30); // A2[30] = '('
-                }
+    /// Encode a single string with abbreviation substitution disabled. Used for the
+    /// abbreviation strings themselves, which must never reference other abbreviations.
+    pub(crate) fn encode_string_raw(&self, s: &str) -> Result<Vec<u8>, CompilerError> {
+        self.encode_string_zchars(s, false)
+    }
 
-                ')' => {
-                    zchars.push(5);
-                    zchars.push(31); // A2[31] = ')'
-                }
+    /// Shared implementation behind `encode_string`/`encode_string_raw`: builds the
+    /// Z-char sequence (substituting abbreviations when `use_abbreviations` is set) and
+    /// packs it into Z-Machine words.
+    fn encode_string_zchars(
+        &self,
+        s: &str,
+        use_abbreviations: bool,
+    ) -> Result<Vec<u8>, CompilerError> {
+        // Z-Machine text encoding per Z-Machine Standard 1.1, Section 3.5.3
+        let chars: Vec<char> = s.chars().collect();
+        let mut zchars = Vec::new();
 
-                // Handle other characters with escape sequence
-                _ => {
-                    // Use escape sequence for characters not in standard alphabets
-                    let unicode_val = ch as u32;
-                    if unicode_val <= 255 {
-                        zchars.push(5); // Shift to A2
-                        zchars.push(6); // Escape sequence
-                        zchars.push(((unicode_val >> 5) & 0x1F) as u8);
-                        zchars.push((unicode_val & 0x1F) as u8);
-                    } else {
-                        return Err(CompilerError::CodeGenError(format!(
-                            "Unicode character '{}' (U+{:04X}) cannot be encoded in Z-Machine text",
-                            ch, unicode_val
-                        )));
-                    }
+        let mut i = 0;
+        while i < chars.len() {
+            if use_abbreviations {
+                if let Some((abbrev_num, phrase_len)) = self.match_abbreviation(&chars[i..]) {
+                    zchars.push((abbrev_num / 32 + 1) as u8);
+                    zchars.push((abbrev_num % 32) as u8);
+                    i += phrase_len;
+                    continue;
                 }
             }
+            zchars.extend(self.char_to_zchars(chars[i])?);
+            i += 1;
         }
 
         // Pack Z-characters into bytes (3 Z-chars per 2 bytes)
@@ -350,20 +327,133 @@ impl ZMachineCodeGen {
         Ok(bytes)
     }
 
+    /// Z-chars for a single character. Alphabet A0 (6-31): abcdefghijklmnopqrstuvwxyz.
+    /// Alphabet A1 (6-31): ABCDEFGHIJKLMNOPQRSTUVWXYZ. Alphabet A2 (6-31):
+    ///  ^0123456789.,!?_#'"/\-:().
+    ///
+    /// Characters up to U+00FF take the fast path: their codepoint doubles as a ZSCII
+    /// code under the A2[6] 10-bit escape (Z-Machine Standard 1.1, Section 3.4).
+    /// Characters above U+00FF go through the ZSCII code `collect_unicode_characters`
+    /// assigned them in the Unicode translation table (Section 3.8.5.4).
+    pub(crate) fn char_to_zchars(&self, ch: char) -> Result<Vec<u8>, CompilerError> {
+        let zchars = match ch {
+            // Space is always Z-character 0
+            ' ' => vec![0],
+
+            // Newline is A2[7] = newline (ZSCII 13)
+            '\n' => vec![5, 7],
+
+            // Alphabet A0: lowercase letters (Z-chars 6-31)
+            'a'..='z' => vec![ch as u8 - b'a' + 6],
+
+            // Alphabet A1: uppercase letters (single-shift with 4, then Z-char 6-31)
+            'A'..='Z' => vec![4, ch as u8 - b'A' + 6],
+
+            // Alphabet A2: digits and punctuation (single-shift with 5, then Z-char 6-31)
+            '0'..='9' => vec![5, ch as u8 - b'0' + 8], // A2[8-17] = "0123456789"
+
+            '.' => vec![5, 18],
+            ',' => vec![5, 19],
+            '!' => vec![5, 20],
+            '?' => vec![5, 21],
+            '_' => vec![5, 22],
+            '#' => vec![5, 23],
+            '\'' => vec![5, 24],
+            '"' => vec![5, 25],
+            '/' => vec![5, 26],
+            '\\' => vec![5, 27],
+            '-' => vec![5, 28],
+            ':' => vec![5, 29],
+            '(' => vec![5, 30],
+            ')' => vec![5, 31],
+
+            // Handle other characters with the A2[6] 10-bit ZSCII escape sequence
+            _ => {
+                let unicode_val = ch as u32;
+                let zscii_code = if unicode_val <= 255 {
+                    unicode_val
+                } else if let Some(&assigned) = self.unicode_chars.get(&ch) {
+                    assigned as u32
+                } else {
+                    return Err(CompilerError::CodeGenError(format!(
+                        "Unicode character '{}' (U+{:04X}) was not assigned a ZSCII code; \
+                         collect_unicode_characters must run over every string before encoding",
+                        ch, unicode_val
+                    )));
+                };
+                vec![
+                    5, // Shift to A2
+                    6, // Escape sequence
+                    ((zscii_code >> 5) & 0x1F) as u8,
+                    (zscii_code & 0x1F) as u8,
+                ]
+            }
+        };
+        Ok(zchars)
+    }
+
+    /// Find the abbreviation (if any) matching the start of `remaining`, preferring the
+    /// longest match. Returns `(abbreviation_number, phrase_char_length)`.
+    fn match_abbreviation(&self, remaining: &[char]) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize)> = None;
+        for (index, phrase) in self.abbreviations.iter().enumerate() {
+            let phrase_chars: Vec<char> = phrase.chars().collect();
+            if phrase_chars.len() > remaining.len() {
+                continue;
+            }
+            if remaining[..phrase_chars.len()] == phrase_chars[..] {
+                let better = match best {
+                    None => true,
+                    Some((_, best_len)) => phrase_chars.len() > best_len,
+                };
+                if better {
+                    best = Some((index, phrase_chars.len()));
+                }
+            }
+        }
+        best
+    }
+
     /// Dictionary Generation Functions
 
-    /// Generate dictionary space with minimal word parsing dictionary
+    /// Generate dictionary space with minimal word parsing dictionary.
+    ///
+    /// Part-of-speech flags and verb indices are written in the Infocom data-byte
+    /// layout so a real Z-Machine parser could classify words without re-deriving
+    /// grammar roles, but this is data format only: `generate_verb_matching` still
+    /// dispatches purely via compile-time dictionary-address comparisons and does
+    /// not yet read these bytes back, nor does `interpreter.rs`.
     pub fn generate_dictionary_space(&mut self, ir: &IrProgram) -> Result<(), CompilerError> {
         debug!("📚 Generating dictionary with grammar verbs and basic commands");
 
-        // Z-Machine dictionary format:
-        // - Word separators count (1 byte): 0
-        // - Entry length (1 byte): 6 for v3
+        // Z-Machine dictionary format (Z-Machine Standard 1.1, Section 13.2):
+        // - Word separators count (1 byte) + that many ZSCII separator bytes
+        // - Entry length (1 byte): 6 for v3 (4 text bytes + 2 flag bytes),
+        //   9 for v4/v5 (6 text bytes + 3 flag bytes)
         // - Number of entries (2 bytes): count
-        // - Entries (6 bytes each for v3): encoded Z-characters (sorted alphabetically)
+        // - Entries (entry_length bytes each): encoded Z-characters (sorted alphabetically)
 
-        // Collect all words that need to be in the dictionary
         use std::collections::BTreeSet;
+        use std::collections::HashMap;
+
+        // Verb index assigned by grammar declaration order, so the runtime can dispatch
+        // straight from a dictionary entry's data byte to the matching grammar rule.
+        let mut verb_indices: HashMap<String, u8> = HashMap::new();
+        for (index, grammar) in ir.grammar.iter().enumerate() {
+            verb_indices
+                .entry(grammar.verb.to_lowercase())
+                .or_insert(index as u8);
+        }
+
+        // Direction words come from room exit tables (e.g. "north", "south").
+        let mut directions: BTreeSet<String> = BTreeSet::new();
+        for room in &ir.rooms {
+            for direction in room.exits.keys() {
+                directions.insert(direction.to_lowercase());
+            }
+        }
+
+        // Collect all words that need to be in the dictionary
         let mut words = BTreeSet::new();
 
         // Add built-in commands
@@ -384,6 +474,41 @@ impl ZMachineCodeGen {
                 }
             }
         }
+        words.extend(directions.iter().cloned());
+
+        // Classify each word's part of speech now that the full vocabulary is known
+        let word_info: HashMap<String, DictionaryWordInfo> = words
+            .iter()
+            .map(|word| {
+                let info = if let Some(&verb_index) = verb_indices.get(word) {
+                    DictionaryWordInfo {
+                        flags: DICT_FLAG_VERB,
+                        verb_index,
+                    }
+                } else if word == "quit" {
+                    DictionaryWordInfo {
+                        flags: DICT_FLAG_VERB,
+                        verb_index: DICT_VERB_INDEX_BUILTIN,
+                    }
+                } else if directions.contains(word) {
+                    DictionaryWordInfo {
+                        flags: DICT_FLAG_DIRECTION,
+                        verb_index: 0,
+                    }
+                } else if Self::is_preposition(word) {
+                    DictionaryWordInfo {
+                        flags: DICT_FLAG_PREPOSITION,
+                        verb_index: 0,
+                    }
+                } else {
+                    DictionaryWordInfo {
+                        flags: DICT_FLAG_NOUN,
+                        verb_index: 0,
+                    }
+                };
+                (word.clone(), info)
+            })
+            .collect();
 
         // BTreeSet automatically keeps words sorted alphabetically
         let word_count = words.len();
@@ -393,16 +518,20 @@ impl ZMachineCodeGen {
         self.dictionary_words = words.iter().cloned().collect();
 
         // Build dictionary data
-        let mut dictionary_data = vec![
-            0x00,                             // Word separators count (0)
-            0x06,                             // Entry length: 6 bytes per entry
-            ((word_count >> 8) & 0xFF) as u8, // Entry count high byte
-            (word_count & 0xFF) as u8,        // Entry count low byte
-        ];
+        let entry_length: u8 = match self.version {
+            ZMachineVersion::V3 => 6,
+            ZMachineVersion::V4 | ZMachineVersion::V5 => 9,
+        };
+        let mut dictionary_data = vec![self.word_separators.len() as u8];
+        dictionary_data.extend(self.word_separators.iter().map(|&c| c as u8));
+        dictionary_data.push(entry_length);
+        dictionary_data.push(((word_count >> 8) & 0xFF) as u8); // Entry count high byte
+        dictionary_data.push((word_count & 0xFF) as u8); // Entry count low byte
 
         // Encode and add each word
         for word in &words {
-            let encoded = self.encode_word_to_zchars(word)?;
+            let info = word_info.get(word).copied().unwrap_or_default();
+            let encoded = self.encode_word_to_zchars(word, &info)?;
             dictionary_data.extend_from_slice(&encoded);
             debug!("📚 Added dictionary entry: '{}' -> {:02x?}", word, encoded);
         }
@@ -421,6 +550,16 @@ impl ZMachineCodeGen {
         Ok(())
     }
 
+    /// Common English prepositions used in Grue grammar patterns; words matching this
+    /// list (and not already classified as a verb or direction) get
+    /// [`DICT_FLAG_PREPOSITION`] instead of the default noun classification.
+    fn is_preposition(word: &str) -> bool {
+        matches!(
+            word,
+            "with" | "in" | "on" | "at" | "to" | "from" | "under" | "behind" | "through" | "into"
+        )
+    }
+
     /// Generate complete dictionary from IR program (future expansion)
     pub fn generate_dictionary(&mut self, _ir: &IrProgram) -> Result<(), CompilerError> {
         debug!("📚 Dictionary generation (placeholder for future expansion)");
@@ -433,19 +572,32 @@ impl ZMachineCodeGen {
     }
 
     /// Encode a word into Z-character format for dictionary entries
-    fn encode_word_to_zchars(&self, word: &str) -> Result<Vec<u8>, CompilerError> {
-        // Simple Z-character encoding for basic ASCII words
-        // Z-characters: a-z = 6-31, space = 5 (Infocom convention)
-        // Each word is packed into 2 16-bit words (4 bytes total for v3, 6 Z-chars)
-
-        // CRITICAL: Use space=5 encoding to match interpreter (Infocom convention)
-        // See CLAUDE.md section on Dictionary Encoding - NEVER use space=0
+    ///
+    /// Z-characters: a-z = 6-31, space = 5 (Infocom convention; NEVER use space=0 here,
+    /// see CLAUDE.md section on Dictionary Encoding). V3 dictionary entries hold 6
+    /// Z-chars packed into 2 words (4 bytes) followed by 2 flag bytes (6-byte entry);
+    /// V4/V5 entries hold 9 Z-chars packed into 3 words (6 bytes) followed by 3 flag
+    /// bytes (9-byte entry), so longer words stay distinct instead of truncating at 6
+    /// characters. `info` supplies the part-of-speech flags and verb index that go into
+    /// the trailing data bytes.
+    fn encode_word_to_zchars(
+        &self,
+        word: &str,
+        info: &DictionaryWordInfo,
+    ) -> Result<Vec<u8>, CompilerError> {
+        let num_zchars = match self.version {
+            ZMachineVersion::V3 => 6,
+            ZMachineVersion::V4 | ZMachineVersion::V5 => 9,
+        };
+        let num_flag_bytes = match self.version {
+            ZMachineVersion::V3 => 2,
+            ZMachineVersion::V4 | ZMachineVersion::V5 => 3,
+        };
 
-        let mut zchars = vec![5u8; 6]; // Initialize with spaces (z-char 5)
+        let mut zchars = vec![5u8; num_zchars]; // Initialize with spaces (z-char 5)
         let word_lower = word.to_lowercase();
 
-        // Encode first 6 characters
-        for (i, ch) in word_lower.chars().enumerate().take(6) {
+        for (i, ch) in word_lower.chars().enumerate().take(num_zchars) {
             let zchar = match ch {
                 'a'..='z' => (ch as u8 - b'a') + 6,
                 ' ' => 5, // Space is z-char 5 (Infocom convention)
@@ -454,23 +606,23 @@ impl ZMachineCodeGen {
             zchars[i] = zchar;
         }
 
-        // Pack 6 z-chars into 2 words (3 chars per word, 5 bits each)
-        // Word 1: chars[0-2], Word 2: chars[3-5]
-        let word1 = ((zchars[0] as u16) << 10) | ((zchars[1] as u16) << 5) | (zchars[2] as u16);
-        let word2 = ((zchars[3] as u16) << 10) | ((zchars[4] as u16) << 5) | (zchars[5] as u16);
-
-        // Set end-of-word bit on word 2 (high bit)
-        let word2 = word2 | 0x8000;
+        // Pack z-chars into 16-bit words (3 chars per word, 5 bits each)
+        let mut result = Vec::with_capacity(zchars.len() / 3 * 2 + num_flag_bytes);
+        let num_words = zchars.len() / 3;
+        for (word_index, chunk) in zchars.chunks(3).enumerate() {
+            let mut word = ((chunk[0] as u16) << 10) | ((chunk[1] as u16) << 5) | (chunk[2] as u16);
+            if word_index + 1 == num_words {
+                word |= 0x8000; // Set end-of-word bit on the last word
+            }
+            result.push((word >> 8) as u8);
+            result.push((word & 0xFF) as u8);
+        }
 
-        // Convert to bytes (big-endian)
-        let result = vec![
-            (word1 >> 8) as u8,
-            (word1 & 0xFF) as u8,
-            (word2 >> 8) as u8,
-            (word2 & 0xFF) as u8,
-            0x80, // Flags byte (high byte)
-            0x00, // Flags byte (low byte)
-        ];
+        // Flag bytes: first byte carries part-of-speech flags, second carries the
+        // grammar verb index (for verbs); remaining bytes (v4/v5 only) are reserved.
+        result.push(info.flags);
+        result.push(info.verb_index);
+        result.extend(std::iter::repeat(0x00).take(num_flag_bytes.saturating_sub(2)));
 
         debug!(
             "📚 Encoded '{}' to Z-chars: {:02x?} (z-chars: {:?})",
@@ -481,20 +633,24 @@ impl ZMachineCodeGen {
 
     /// String Utility Functions
 
+    /// Append `(id, s)` to `strings` and index it in `string_interner`, keeping both in
+    /// sync regardless of which call site is doing the inserting.
+    pub(crate) fn record_string(&mut self, id: IrId, s: &str) {
+        self.strings.push((id, s.to_string()));
+        self.string_interner.record(id, s);
+    }
+
     /// Find or create a string ID for the given string
     pub fn find_or_create_string_id(&mut self, s: &str) -> Result<IrId, CompilerError> {
-        // Check if string already exists
-        for (id, existing_string) in &self.strings {
-            if existing_string == s {
-                return Ok(*id);
-            }
+        if let Some(id) = self.string_interner.id_for(s) {
+            return Ok(id);
         }
 
         // Create new string ID
         let new_id: IrId = self.next_string_id;
         self.next_string_id += 1;
 
-        self.strings.push((new_id, s.to_string()));
+        self.record_string(new_id, s);
         debug!("🔤 Created new string ID {} for '{}'", new_id, s);
 
         Ok(new_id)
@@ -502,11 +658,9 @@ impl ZMachineCodeGen {
 
     /// Get string value from IR ID
     pub fn get_string_value(&self, ir_id: IrId) -> Result<String, CompilerError> {
-        // Check strings collection first
-        for (id, string) in &self.strings {
-            if *id == ir_id {
-                return Ok(string.clone());
-            }
+        // Check the interned string table first
+        if let Some(string) = self.string_interner.value_for(ir_id) {
+            return Ok(string.clone());
         }
 
         // Check IR ID to string mapping
@@ -535,6 +689,13 @@ impl ZMachineCodeGen {
     }
 
     /// Address and Space Management
+    ///
+    /// NOTE: The offsets recorded by `allocate_string_space`/`allocate_string_address`/
+    /// `allocate_dictionary_space` below are relative to their own space, not final file
+    /// addresses; `pack_string_address` likewise only packs whatever byte address it's
+    /// given. The debug-info sidecar (`codegen_debug::populate_debug_info`) reads the
+    /// same `string_offsets` map once `final_string_base` is known in Phase 3, so it
+    /// doesn't need separate bookkeeping here.
 
     /// Allocate space in string space and return offset
     pub fn allocate_string_space(
@@ -36,6 +36,7 @@ mod codegen_tests {
                 symbol_ids: std::collections::HashSet::new(),
                 expression_ids: std::collections::HashSet::new(),
             },
+            word_separators: None,
         }
     }
 
@@ -1,5 +1,6 @@
 // Compiler Error Handling
 
+use crate::grue_compiler::ast::Span;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -167,6 +168,71 @@ impl CompilerError {
                 | CompilerError::StringTooLong(_)
         )
     }
+
+    /// The source character offset this error points at, if it carries one.
+    /// `InvalidOpcode`'s "position" is a *bytecode* address rather than a
+    /// source offset, so it's deliberately excluded here.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            CompilerError::LexicalError(_, pos)
+            | CompilerError::UnexpectedCharacter(_, pos)
+            | CompilerError::UnterminatedString(pos)
+            | CompilerError::ParseError(_, pos)
+            | CompilerError::UnexpectedToken(_, pos)
+            | CompilerError::ExpectedToken(_, _, pos)
+            | CompilerError::SemanticError(_, pos)
+            | CompilerError::UndefinedSymbol(_, pos)
+            | CompilerError::DuplicateSymbol(_, pos)
+            | CompilerError::TypeMismatch(_, _, pos) => Some(*pos),
+            _ => None,
+        }
+    }
+
+    /// Render this error as `Display` would, followed by a caret-underlined
+    /// snippet of the source line it points at (when it carries a position).
+    /// Errors with no source position (code generation/runtime/IO errors)
+    /// fall back to plain `Display` formatting.
+    pub fn render_with_source(&self, source: &str) -> String {
+        match self.position() {
+            Some(pos) => {
+                let span = Span::from_position(source, pos);
+                highlight_error(source, &span, &self.to_string())
+            }
+            None => self.to_string(),
+        }
+    }
 }
 
 impl std::error::Error for CompilerError {}
+
+/// Collapse a batch of recovered parse errors (see `Parser::parse`) down to
+/// the first one. `GrueCompiler::compile_to_ir`/`compile_with_options` do
+/// *not* use this - they return the whole `Vec<CompilerError>` so callers
+/// see every parse error, not just the first - this is for test helpers
+/// and other callers that only want a single representative error.
+impl From<Vec<CompilerError>> for CompilerError {
+    fn from(mut errors: Vec<CompilerError>) -> Self {
+        errors
+            .drain(..)
+            .next()
+            .expect("parser only returns Err with at least one error")
+    }
+}
+
+/// Render `message` with a copy of the source line `span` starts on, followed
+/// by a row of `^` carets under the offending column - the standard
+/// "pinpointed diagnostic" format (as used by rustc, clang, etc).
+pub fn highlight_error(source: &str, span: &Span, message: &str) -> String {
+    let source_line = source.lines().nth(span.line - 1).unwrap_or("");
+    let caret_count = (span.end.saturating_sub(span.start)).max(1);
+    let caret_line = format!(
+        "{}{}",
+        " ".repeat(span.col.saturating_sub(1)),
+        "^".repeat(caret_count)
+    );
+
+    format!(
+        "{}\n  --> line {}, column {}\n{}\n{}",
+        message, span.line, span.col, source_line, caret_line
+    )
+}
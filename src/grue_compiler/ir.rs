@@ -106,6 +106,10 @@ pub struct IrProgram {
     /// System messages catalog for localization support
     /// Maps message keys to localized text (e.g., "no_understand" -> "I don't understand that.")
     pub system_messages: IndexMap<String, String>,
+    /// Word-separator characters from a `grammar { separators: [...] }` declaration, if
+    /// any; threaded into `ZMachineCodeGen::word_separators` by `GrueCompiler::compile_with_options`.
+    /// `None` means no grammar block set them, so codegen keeps its own default.
+    pub word_separators: Option<Vec<char>>,
 }
 
 impl IrProgram {
@@ -964,6 +968,7 @@ impl IrProgram {
             property_manager: PropertyManager::new(), // Initialize property manager
             expression_types: IndexMap::new(), // NEW: Initialize expression types for StringAddress system
             system_messages: IndexMap::new(),  // NEW: Initialize system messages catalog
+            word_separators: None,
         }
     }
 
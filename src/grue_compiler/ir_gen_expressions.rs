@@ -113,6 +113,7 @@ impl super::IrGenerator {
                 left,
                 operator,
                 right,
+                ..
             } => {
                 let left_id = self.generate_expression(*left, block)?;
                 let right_id = self.generate_expression(*right, block)?;
@@ -127,7 +128,9 @@ impl super::IrGenerator {
 
                 Ok(temp_id)
             }
-            Expr::Unary { operator, operand } => {
+            Expr::Unary {
+                operator, operand, ..
+            } => {
                 let operand_id = self.generate_expression(*operand, block)?;
                 let temp_id = self.next_id();
 
@@ -139,7 +142,9 @@ impl super::IrGenerator {
 
                 Ok(temp_id)
             }
-            Expr::FunctionCall { name, arguments } => {
+            Expr::FunctionCall {
+                name, arguments, ..
+            } => {
                 // Generate arguments first
                 let mut arg_temps = Vec::new();
                 for arg in arguments {
@@ -947,7 +952,7 @@ impl super::IrGenerator {
                         return match source {
                             // Arrays removed - no variables are arrays anymore
                             VariableSource::ObjectTreeRoot(_) => false, // Contents result - NOT an array
-                            VariableSource::Scalar(_) => false,         // Scalar value - NOT an array
+                            VariableSource::Scalar(_) => false, // Scalar value - NOT an array
                         };
                     }
                 }
@@ -2,6 +2,14 @@
 //
 // Extracted from ir_generator.rs as part of modularization effort.
 // Handles world generation, object creation, placement, and numbering.
+//
+// NOTE: this file is not declared with a `mod` statement anywhere
+// (`ir_generator.rs` only declares `ir_gen_functions`, `ir_gen_grammar`, and
+// `ir_gen_rooms`), so none of the `impl IrGenerator` blocks below are
+// compiled. The real object-generation logic lives in `generate_object` in
+// `ir_generator.rs`. Don't hand-sync changes here - either wire this file in
+// with `mod ir_gen_objects;` and delete the duplicate in `ir_generator.rs`,
+// or delete this file.
 
 use crate::grue_compiler::error::CompilerError;
 
@@ -176,6 +184,8 @@ impl IrGenerator {
             .get(&obj.identifier)
             .ok_or_else(|| CompilerError::UndefinedSymbol(obj.identifier.clone(), 0))?;
 
+        let description = self.resolve_description(&obj.description)?;
+
         // Convert named attributes to Z-Machine attributes
         // These are attributes declared with syntax: attributes: ["openable", "container"]
         let mut attributes = IrAttributes::new();
@@ -244,7 +254,7 @@ impl IrGenerator {
 
         // Set standard properties using computed short_name (not obj.identifier!)
         properties.set_string(StandardProperty::ShortName as u8, short_name.clone());
-        properties.set_string(StandardProperty::Description as u8, obj.description.clone());
+        properties.set_string(StandardProperty::Description as u8, description.clone());
 
         // Convert AST properties to Z-Machine properties using property manager
         for (prop_name, prop_value) in &obj.properties {
@@ -279,6 +289,20 @@ impl IrGenerator {
                         properties.set_word(prop_num, room_num);
                     }
                 }
+                crate::grue_compiler::ast::PropertyValue::Array(_) => {
+                    log::warn!(
+                        "Array property '{}' on object '{}' is not yet supported in code generation",
+                        prop_name,
+                        obj.identifier
+                    );
+                }
+                crate::grue_compiler::ast::PropertyValue::Expr(_) => {
+                    log::warn!(
+                        "Property '{}' on object '{}' was not constant-folded before code generation",
+                        prop_name,
+                        obj.identifier
+                    );
+                }
             }
         }
 
@@ -314,6 +338,20 @@ impl IrGenerator {
                 crate::grue_compiler::ast::PropertyValue::Boolean(_) => {
                     // Already handled above, but included for exhaustiveness
                 }
+                crate::grue_compiler::ast::PropertyValue::Array(_) => {
+                    log::warn!(
+                        "Array numbered property #{} on object '{}' is not yet supported in code generation",
+                        prop_num,
+                        obj.identifier
+                    );
+                }
+                crate::grue_compiler::ast::PropertyValue::Expr(_) => {
+                    log::warn!(
+                        "Numbered property #{} on object '{}' was not constant-folded before code generation",
+                        prop_num,
+                        obj.identifier
+                    );
+                }
             }
         }
 
@@ -347,7 +385,7 @@ impl IrGenerator {
             name: obj.identifier,
             short_name,
             names: obj.names,
-            description: obj.description,
+            description,
             attributes,
             properties,
             parent: parent_id,
@@ -360,6 +398,24 @@ impl IrGenerator {
         Ok(result)
     }
 
+    /// Reduce a `desc:` expression down to the literal string Z-Machine object
+    /// text requires. Only string literals are supported today - anything
+    /// else (an identifier, a call, unfolded arithmetic) depends on a value
+    /// that isn't known until runtime, which static object text can't
+    /// represent yet.
+    fn resolve_description(
+        &self,
+        expr: &crate::grue_compiler::ast::Expr,
+    ) -> Result<String, CompilerError> {
+        match expr {
+            crate::grue_compiler::ast::Expr::String(s) => Ok(s.clone()),
+            other => Err(CompilerError::CodeGenError(format!(
+                "object description must be a string literal, found {:?}",
+                other
+            ))),
+        }
+    }
+
     /// Generate InsertObj instructions from room_objects mapping for init block
     ///
     /// Converts room object hierarchies to InsertObj instructions to establish object tree.
@@ -443,7 +499,10 @@ impl IrGenerator {
     /// - Object number #1 (assigned during codegen)
     /// - Initial location in first room
     /// - Standard properties (location, description, quit_pending)
-    pub(super) fn add_player_object(&mut self, ir_program: &mut IrProgram) -> Result<(), CompilerError> {
+    pub(super) fn add_player_object(
+        &mut self,
+        ir_program: &mut IrProgram,
+    ) -> Result<(), CompilerError> {
         // Create player object with ID 9999 (high ID to avoid conflicts)
         let player_id = 9999u32;
 
@@ -20,11 +20,11 @@ pub struct IrGenerator {
     pub(super) symbol_ids: IndexMap<String, IrId>, // Symbol name -> IR ID mapping
     pub(super) current_locals: Vec<IrLocal>,       // Track local variables in current function
     pub(super) next_local_slot: u8,                // Next available local variable slot
-    builtin_functions: IndexMap<IrId, String>, // Function ID -> Function name for builtins
+    builtin_functions: IndexMap<IrId, String>,     // Function ID -> Function name for builtins
     pub(super) object_numbers: IndexMap<String, u16>, // Object name -> Object number mapping
-    object_counter: u16,                // Next available object number (starts at 2, player is 1)
-    property_manager: PropertyManager,  // Manages property numbering and inheritance
-    id_registry: IrIdRegistry,          // NEW: Track all IR IDs for debugging and mapping
+    object_counter: u16, // Next available object number (starts at 2, player is 1)
+    property_manager: PropertyManager, // Manages property numbering and inheritance
+    id_registry: IrIdRegistry, // NEW: Track all IR IDs for debugging and mapping
     variable_sources: IndexMap<IrId, VariableSource>, // Track variable origins for iteration strategy
     expression_types: IndexMap<IrId, Type>, // NEW: Track expression result types for StringAddress system
     /// Mapping of room names to objects contained within them
@@ -324,6 +324,9 @@ impl IrGenerator {
             deferred_grammar.len()
         );
         for grammar in deferred_grammar {
+            if let Some(separators) = grammar.separators.clone() {
+                ir_program.word_separators = Some(separators);
+            }
             let ir_grammar = self.generate_grammar(grammar)?;
             ir_program.grammar.extend(ir_grammar);
         }
@@ -497,6 +500,9 @@ impl IrGenerator {
                 self.generate_world(world, ir_program)?;
             }
             Item::Grammar(grammar) => {
+                if let Some(separators) = grammar.separators.clone() {
+                    ir_program.word_separators = Some(separators);
+                }
                 let ir_grammar = self.generate_grammar(grammar)?;
                 ir_program.grammar.extend(ir_grammar);
             }
@@ -680,6 +686,8 @@ impl IrGenerator {
             .get(&obj.identifier)
             .ok_or_else(|| CompilerError::UndefinedSymbol(obj.identifier.clone(), 0))?;
 
+        let description = self.resolve_description(&obj.description)?;
+
         // Convert named attributes to Z-Machine attributes
         // These are attributes declared with syntax: attributes: ["openable", "container"]
         let mut attributes = IrAttributes::new();
@@ -748,7 +756,7 @@ impl IrGenerator {
 
         // Set standard properties using computed short_name (not obj.identifier!)
         properties.set_string(StandardProperty::ShortName as u8, short_name.clone());
-        properties.set_string(StandardProperty::Description as u8, obj.description.clone());
+        properties.set_string(StandardProperty::Description as u8, description.clone());
 
         // Convert AST properties to Z-Machine properties using property manager
         for (prop_name, prop_value) in &obj.properties {
@@ -783,6 +791,16 @@ impl IrGenerator {
                         properties.set_word(prop_num, room_num);
                     }
                 }
+                crate::grue_compiler::ast::PropertyValue::Array(_) => {
+                    return Err(CompilerError::CodeGenError(format!(
+                        "array property '{}' on object '{}' is not supported in code generation",
+                        prop_name, obj.identifier
+                    )));
+                }
+                crate::grue_compiler::ast::PropertyValue::Expr(expr) => {
+                    let obj_num = self.resolve_expr_property(expr, prop_name, &obj.identifier)?;
+                    properties.set_word(prop_num, obj_num);
+                }
             }
         }
 
@@ -818,6 +836,20 @@ impl IrGenerator {
                 crate::grue_compiler::ast::PropertyValue::Boolean(_) => {
                     // Already handled above, but included for exhaustiveness
                 }
+                crate::grue_compiler::ast::PropertyValue::Array(_) => {
+                    return Err(CompilerError::CodeGenError(format!(
+                        "array numbered property #{} on object '{}' is not supported in code generation",
+                        prop_num, obj.identifier
+                    )));
+                }
+                crate::grue_compiler::ast::PropertyValue::Expr(expr) => {
+                    let obj_num = self.resolve_expr_property(
+                        expr,
+                        &format!("#{}", prop_num),
+                        &obj.identifier,
+                    )?;
+                    properties.set_word(*prop_num, obj_num);
+                }
             }
         }
 
@@ -851,7 +883,7 @@ impl IrGenerator {
             name: obj.identifier,
             short_name,
             names: obj.names,
-            description: obj.description,
+            description,
             attributes,
             properties,
             parent: parent_id,
@@ -864,6 +896,49 @@ impl IrGenerator {
         Ok(result)
     }
 
+    /// Reduce a `desc:` expression down to the literal string Z-Machine object
+    /// text requires. Only string literals are supported today - anything
+    /// else (an identifier, a call, unfolded arithmetic) depends on a value
+    /// that isn't known until runtime, which static object text can't
+    /// represent yet.
+    fn resolve_description(
+        &self,
+        expr: &crate::grue_compiler::ast::Expr,
+    ) -> Result<String, CompilerError> {
+        match expr {
+            crate::grue_compiler::ast::Expr::String(s) => Ok(s.clone()),
+            other => Err(CompilerError::CodeGenError(format!(
+                "object description must be a string literal, found {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Resolve a property value the parser/`ast_optimizer` left as a raw
+    /// expression - an identifier reference (e.g. `initial_location:
+    /// west_house`) or arithmetic the optimizer couldn't fold to a literal -
+    /// into the object number code generation needs. A bare identifier that
+    /// names a known object or room resolves the same way `PropertyValue::
+    /// Object`/`Room` already do; anything else is a hard error rather than
+    /// a dropped property, since silently omitting it would miscompile the
+    /// game with no diagnostic.
+    fn resolve_expr_property(
+        &self,
+        expr: &crate::grue_compiler::ast::Expr,
+        prop_name: &str,
+        obj_name: &str,
+    ) -> Result<u16, CompilerError> {
+        if let crate::grue_compiler::ast::Expr::Identifier(name) = expr {
+            if let Some(&num) = self.object_numbers.get(name) {
+                return Ok(num);
+            }
+        }
+        Err(CompilerError::CodeGenError(format!(
+            "property '{}' on object '{}' did not resolve to a constant value or a known object/room reference, found {:?}",
+            prop_name, obj_name, expr
+        )))
+    }
+
     pub(super) fn register_object_and_nested(
         &mut self,
         obj: &crate::grue_compiler::ast::ObjectDecl,
@@ -1909,6 +1984,7 @@ impl IrGenerator {
                 left,
                 operator,
                 right,
+                ..
             } => {
                 let left_id = self.generate_expression(*left, block)?;
                 let right_id = self.generate_expression(*right, block)?;
@@ -1923,7 +1999,9 @@ impl IrGenerator {
 
                 Ok(temp_id)
             }
-            Expr::Unary { operator, operand } => {
+            Expr::Unary {
+                operator, operand, ..
+            } => {
                 let operand_id = self.generate_expression(*operand, block)?;
                 let temp_id = self.next_id();
 
@@ -1935,7 +2013,9 @@ impl IrGenerator {
 
                 Ok(temp_id)
             }
-            Expr::FunctionCall { name, arguments } => {
+            Expr::FunctionCall {
+                name, arguments, ..
+            } => {
                 // Generate arguments first
                 let mut arg_temps = Vec::new();
                 for arg in arguments {
@@ -3123,6 +3203,6 @@ impl IrGenerator {
 }
 
 // Extracted modules for functional organization
+mod ir_gen_functions;
 mod ir_gen_grammar;
 mod ir_gen_rooms;
-mod ir_gen_functions;
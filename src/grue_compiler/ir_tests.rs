@@ -11,7 +11,7 @@ mod ir_tests {
     fn generate_ir_from_source(source: &str) -> Result<IrProgram, CompilerError> {
         let mut lexer = Lexer::new(source);
         let tokens = lexer.tokenize()?;
-        let mut parser = Parser::new(tokens);
+        let mut parser = Parser::new(tokens, source);
         let ast = parser.parse()?;
         let mut ir_generator = IrGenerator::new();
         ir_generator.generate(ast)
@@ -824,7 +824,7 @@ mod ir_tests {
         let mut ir_generator = IrGenerator::new();
         let mut lexer = crate::grue_compiler::lexer::Lexer::new(source);
         let tokens = lexer.tokenize().unwrap();
-        let mut parser = crate::grue_compiler::parser::Parser::new(tokens);
+        let mut parser = crate::grue_compiler::parser::Parser::new(tokens, source);
         let ast = parser.parse().unwrap();
         let _ = ir_generator.generate(ast).unwrap();
 
@@ -286,6 +286,16 @@ impl Lexer {
                         TokenKind::StringLiteral(string_value)
                     }
 
+                    // Raw string literals: r"..." or r#"..."# (no escape processing,
+                    // for prose containing backslashes). Only treated as a raw-string
+                    // prefix when 'r' is immediately followed by '"' or '#' - otherwise
+                    // it's an ordinary identifier starting with 'r' (e.g. "room").
+                    'r' if matches!(self.peek_char(), Some('"') | Some('#')) => {
+                        self.advance();
+                        let string_value = self.read_raw_string(start_pos)?;
+                        TokenKind::StringLiteral(string_value)
+                    }
+
                     // Numbers
                     ch if ch.is_ascii_digit() => {
                         let number = self.read_number()?;
@@ -321,6 +331,11 @@ impl Lexer {
         }
     }
 
+    /// Look at the character after `current_char` without consuming anything.
+    fn peek_char(&self) -> Option<char> {
+        self.input.get(self.position + 1).copied()
+    }
+
     fn advance(&mut self) {
         if let Some('\n') = self.current_char {
             self.line += 1;
@@ -384,10 +399,19 @@ impl Lexer {
                             value.push('"');
                             self.advance();
                         }
-                        Some(ch) => {
-                            value.push(ch);
+                        Some('0') => {
+                            value.push('\0');
                             self.advance();
                         }
+                        Some(ch) => {
+                            return Err(CompilerError::LexicalError(
+                                format!(
+                                    "Unknown escape sequence '\\{}' at column {}",
+                                    ch, self.column
+                                ),
+                                self.position,
+                            ));
+                        }
                         None => return Err(CompilerError::UnterminatedString(start_pos)),
                     }
                 }
@@ -401,6 +425,53 @@ impl Lexer {
         Err(CompilerError::UnterminatedString(start_pos))
     }
 
+    /// Read a raw string literal `r"..."` / `r#"..."#` (the leading `r` has already been
+    /// consumed). No escape processing: a closing `"` only ends the literal when followed
+    /// by the same number of `#` characters the opening used, so `r#"say "hi""#` can embed
+    /// quotes, and `\` never needs escaping - useful for IF prose full of backslashes.
+    fn read_raw_string(&mut self, start_pos: usize) -> Result<String, CompilerError> {
+        let mut hash_count = 0;
+        while self.current_char == Some('#') {
+            hash_count += 1;
+            self.advance();
+        }
+
+        if self.current_char != Some('"') {
+            return Err(CompilerError::LexicalError(
+                format!(
+                    "Expected '\"' to begin raw string literal at column {}",
+                    self.column
+                ),
+                self.position,
+            ));
+        }
+        self.advance();
+
+        let mut value = String::new();
+        loop {
+            match self.current_char {
+                Some('"') if self.raw_string_closes(hash_count) => {
+                    self.advance();
+                    for _ in 0..hash_count {
+                        self.advance();
+                    }
+                    return Ok(value);
+                }
+                Some(ch) => {
+                    value.push(ch);
+                    self.advance();
+                }
+                None => return Err(CompilerError::UnterminatedString(start_pos)),
+            }
+        }
+    }
+
+    /// Whether the closing `"` at `current_char` is followed by `hash_count` `#`
+    /// characters, as a raw string's closing delimiter requires.
+    fn raw_string_closes(&self, hash_count: usize) -> bool {
+        (0..hash_count).all(|i| self.input.get(self.position + 1 + i) == Some(&'#'))
+    }
+
     fn read_number(&mut self) -> Result<i16, CompilerError> {
         let mut value = String::new();
 
@@ -491,6 +562,18 @@ impl Lexer {
     }
 }
 
+/// Render a token stream as a stable, one-token-per-line snapshot, with each
+/// token's 1-based line:column position alongside its kind. Used by
+/// `--dump-tokens` and by tests that want to assert against tokenization
+/// output without a hand-written `match` ladder per token.
+pub fn format_tokens(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|token| format!("{}:{} {:?}", token.line, token.column, token.kind))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 #[path = "lexer_tests.rs"]
 mod tests;
@@ -303,6 +303,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_null_escape() {
+        let tokens = tokenize_input(r#""a\0b""#);
+        assert_eq!(
+            tokens,
+            vec![TokenKind::StringLiteral("a\0b".to_string()), TokenKind::EOF,]
+        );
+    }
+
+    #[test]
+    fn test_unknown_escape_error() {
+        let mut lexer = Lexer::new(r#""bad\qescape""#);
+        let result = lexer.tokenize();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_raw_string() {
+        let tokens = tokenize_input(r####"r"line one\nline two no escapes""####);
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::StringLiteral("line one\\nline two no escapes".to_string()),
+                TokenKind::EOF,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_string_with_hashes_embeds_quotes() {
+        let tokens = tokenize_input(r####"r#"she said "hi" to him"#"####);
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::StringLiteral("she said \"hi\" to him".to_string()),
+                TokenKind::EOF,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_identifier_starting_with_r_unaffected() {
+        let tokens = tokenize_input("room roomy r_value");
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::Room,
+                TokenKind::Identifier("roomy".to_string()),
+                TokenKind::Identifier("r_value".to_string()),
+                TokenKind::EOF,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_raw_string() {
+        let mut lexer = Lexer::new(r####"r#"no closing delimiter""####);
+        let result = lexer.tokenize();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_keyword_vs_identifier_boundary() {
         // Test that keywords are recognized correctly when adjacent to other tokens
@@ -335,4 +396,22 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_format_tokens_snapshot() {
+        use crate::grue_compiler::lexer::format_tokens;
+
+        let mut lexer = Lexer::new("let x = 1;");
+        let tokens = lexer.tokenize().unwrap();
+
+        assert_eq!(
+            format_tokens(&tokens),
+            "1:1 Let\n\
+             1:5 Identifier(\"x\")\n\
+             1:7 Equal\n\
+             1:9 IntegerLiteral(1)\n\
+             1:10 Semicolon\n\
+             1:11 EOF"
+        );
+    }
 }
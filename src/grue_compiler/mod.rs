@@ -1,9 +1,12 @@
 // Grue Compiler Module
 // Main compiler infrastructure for the Grue language
 
+pub mod analyzer;
 pub mod ast;
+pub mod ast_optimizer;
 pub mod codegen;
 pub mod codegen_builtins;
+pub mod codegen_debug;
 pub mod codegen_headers;
 pub mod codegen_instructions;
 pub mod codegen_objects;
@@ -29,8 +32,35 @@ mod unresolved_reference_tests;
 
 use std::fmt;
 
+pub use ast_optimizer::OptLevel;
 pub use error::CompilerError;
 
+/// Knobs for an individual [`GrueCompiler::compile_with_options`] call.
+#[derive(Debug, Clone, Copy)]
+pub struct CompilerOptions {
+    /// How aggressively to constant-fold and dead-branch-eliminate the AST
+    /// before code generation. See `ast_optimizer`.
+    pub opt_level: OptLevel,
+    /// When true, also collect a DWARF-style debug-info sidecar (see
+    /// `codegen_debug`).
+    pub debug_info: bool,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        CompilerOptions {
+            // Constant folding isn't just a size optimization here: an
+            // object property like `weight: 3 * 2` only becomes a concrete
+            // value once this pass runs, and code generation has no other
+            // way to turn an arithmetic expression into a property value.
+            // So callers get folding by default even if they never opted
+            // into optimization for its own sake.
+            opt_level: OptLevel::Basic,
+            debug_info: false,
+        }
+    }
+}
+
 /// Z-Machine version enum
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ZMachineVersion {
@@ -67,22 +97,41 @@ impl GrueCompiler {
     }
 
     /// Compile Grue source code to IR only (for debugging)
-    pub fn compile_to_ir(&self, source: &str) -> Result<ir::IrProgram, CompilerError> {
+    ///
+    /// Returns every error the pipeline collected rather than just the
+    /// first one: parsing and type checking both recover from errors to
+    /// keep going, and a caller that only sees the first error can't tell
+    /// the user about the rest without re-running the compiler.
+    pub fn compile_to_ir(&self, source: &str) -> Result<ir::IrProgram, Vec<CompilerError>> {
         // Phase 1: Lexical Analysis
         let mut lexer = lexer::Lexer::new(source);
-        let tokens = lexer.tokenize()?;
+        let tokens = lexer.tokenize().map_err(|err| vec![err])?;
 
         // Phase 2: Parsing
-        let mut parser = parser::Parser::new(tokens);
+        let mut parser = parser::Parser::new(tokens, source);
         let ast = parser.parse()?;
 
         // Phase 3: Semantic Analysis
-        let mut analyzer = semantic::SemanticAnalyzer::new();
-        let analyzed_ast = analyzer.analyze(ast)?;
+        let mut semantic_analyzer = semantic::SemanticAnalyzer::new();
+        let analyzed_ast = semantic_analyzer.analyze(ast).map_err(|err| vec![err])?;
+
+        // Phase 3b: Type Checking
+        let type_errors = analyzer::Analyzer::new().analyze(&analyzed_ast);
+        if !type_errors.is_empty() {
+            return Err(type_errors);
+        }
+
+        // Phase 3c: AST Optimization. `compile_to_ir` takes no `CompilerOptions`,
+        // so use the same default `compile`/`compile_with_options` use - see
+        // `CompilerOptions::default` for why this isn't optional.
+        let analyzed_ast =
+            ast_optimizer::optimize(analyzed_ast, CompilerOptions::default().opt_level);
 
         // Phase 4: IR Generation
         let mut ir_generator = ir::IrGenerator::new();
-        let ir_program = ir_generator.generate(analyzed_ast)?;
+        let ir_program = ir_generator
+            .generate(analyzed_ast)
+            .map_err(|err| vec![err])?;
 
         Ok(ir_program)
     }
@@ -92,25 +141,75 @@ impl GrueCompiler {
         &self,
         source: &str,
         version: ZMachineVersion,
-    ) -> Result<(Vec<u8>, codegen::ZMachineCodeGen), CompilerError> {
+    ) -> Result<(Vec<u8>, codegen::ZMachineCodeGen), Vec<CompilerError>> {
+        self.compile_with_debug_info(source, version, false)
+    }
+
+    /// Same as [`compile`], but when `debug_info` is true also collects a DWARF-style
+    /// debug-info sidecar (see `codegen_debug`) that the caller can serialize via
+    /// `ZMachineCodeGen::write_debug_info` once compilation succeeds.
+    pub fn compile_with_debug_info(
+        &self,
+        source: &str,
+        version: ZMachineVersion,
+        debug_info: bool,
+    ) -> Result<(Vec<u8>, codegen::ZMachineCodeGen), Vec<CompilerError>> {
+        self.compile_with_options(
+            source,
+            version,
+            CompilerOptions {
+                debug_info,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Same as [`compile`], but with full control over optimization level and
+    /// debug-info collection via [`CompilerOptions`].
+    ///
+    /// Returns every error the pipeline collected rather than just the
+    /// first one: see [`compile_to_ir`](Self::compile_to_ir) for why.
+    pub fn compile_with_options(
+        &self,
+        source: &str,
+        version: ZMachineVersion,
+        options: CompilerOptions,
+    ) -> Result<(Vec<u8>, codegen::ZMachineCodeGen), Vec<CompilerError>> {
         // Phase 1: Lexical Analysis
         let mut lexer = lexer::Lexer::new(source);
-        let tokens = lexer.tokenize()?;
+        let tokens = lexer.tokenize().map_err(|err| vec![err])?;
 
         // Phase 2: Parsing
-        let mut parser = parser::Parser::new(tokens);
+        let mut parser = parser::Parser::new(tokens, source);
         let ast = parser.parse()?;
 
         // Phase 3: Semantic Analysis
-        let mut analyzer = semantic::SemanticAnalyzer::new();
-        let analyzed_ast = analyzer.analyze(ast)?;
+        let mut semantic_analyzer = semantic::SemanticAnalyzer::new();
+        let analyzed_ast = semantic_analyzer.analyze(ast).map_err(|err| vec![err])?;
+
+        // Phase 3b: Type Checking
+        let type_errors = analyzer::Analyzer::new().analyze(&analyzed_ast);
+        if !type_errors.is_empty() {
+            return Err(type_errors);
+        }
+
+        // Phase 3c: AST Optimization
+        let analyzed_ast = ast_optimizer::optimize(analyzed_ast, options.opt_level);
 
         // Phase 4: IR Generation
         let mut ir_generator = ir::IrGenerator::new();
-        let ir_program = ir_generator.generate(analyzed_ast)?;
+        let ir_program = ir_generator
+            .generate(analyzed_ast)
+            .map_err(|err| vec![err])?;
 
         // Phase 5: Code Generation
         let mut code_generator = codegen::ZMachineCodeGen::new(version);
+        if options.debug_info {
+            code_generator.enable_debug_info();
+        }
+        if let Some(separators) = &ir_program.word_separators {
+            code_generator.word_separators = separators.clone();
+        }
 
         // Transfer builtin function information from IR generator to code generator
         log::debug!(
@@ -129,12 +228,21 @@ impl GrueCompiler {
         // Transfer object numbers from IR generator to code generator
         code_generator.set_object_numbers(ir_generator.get_object_numbers().clone());
 
-        let story_data = code_generator.generate_complete_game_image(ir_program)?;
+        let story_data = code_generator
+            .generate_complete_game_image(ir_program)
+            .map_err(|err| vec![err])?;
 
         Ok((story_data, code_generator))
     }
 }
 
+/// Render a parsed `Program` as a stable, pretty-printed snapshot. Used by
+/// `--dump-ast` and by tests that want to assert against parser output
+/// without a hand-written `match` ladder per node.
+pub fn dump_ast(program: &ast::Program) -> String {
+    format!("{:#?}", program)
+}
+
 /// Print IR program in a human-readable format
 pub fn print_ir(ir: &ir::IrProgram) {
     println!("Program Mode: {:?}", ir.program_mode);
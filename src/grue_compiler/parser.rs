@@ -5,17 +5,44 @@ use crate::grue_compiler::error::CompilerError;
 use crate::grue_compiler::lexer::{Token, TokenKind};
 use std::collections::HashMap;
 
+/// Synchronizing tokens for the top-level item loop: once an item fails to
+/// parse, skip ahead until one of these reappears so the next item still has
+/// a chance to parse cleanly.
+const ITEM_RECOVERY_SET: &[TokenKind] = &[
+    TokenKind::World,
+    TokenKind::Grammar,
+    TokenKind::Function,
+    TokenKind::Init,
+];
+
+/// Synchronizing tokens for a malformed room inside `world { ... }`.
+const ROOM_RECOVERY_SET: &[TokenKind] = &[TokenKind::Room, TokenKind::RightBrace];
+
+/// Synchronizing tokens for a malformed verb inside `grammar { ... }`.
+const VERB_RECOVERY_SET: &[TokenKind] = &[TokenKind::Verb, TokenKind::RightBrace];
+
 pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
+    source: String,
+    errors: Vec<CompilerError>,
 }
 
 impl Parser {
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, current: 0 }
+    pub fn new(tokens: Vec<Token>, source: &str) -> Self {
+        Parser {
+            tokens,
+            current: 0,
+            source: source.to_string(),
+            errors: Vec::new(),
+        }
     }
 
-    pub fn parse(&mut self) -> Result<Program, CompilerError> {
+    /// Parse the whole program, recovering from malformed top-level items,
+    /// rooms, and verbs instead of stopping at the first one. Every error
+    /// encountered along the way is collected rather than discarded, so a
+    /// file with several unrelated mistakes reports all of them in one run.
+    pub fn parse(&mut self) -> Result<Program, Vec<CompilerError>> {
         let mut items = Vec::new();
 
         while !self.is_at_end() {
@@ -25,10 +52,36 @@ impl Parser {
                 continue;
             }
 
-            items.push(self.parse_item()?);
+            match self.parse_item() {
+                Ok(item) => items.push(item),
+                Err(err) => {
+                    self.errors.push(err);
+                    self.synchronize(ITEM_RECOVERY_SET);
+                }
+            }
+        }
+
+        if self.errors.is_empty() {
+            Ok(Program { items })
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    /// Skip tokens until the next one is a member of `recovery_set` (or the
+    /// input is exhausted), always consuming at least one token first so a
+    /// parse failure that didn't advance the cursor still makes progress.
+    fn synchronize(&mut self, recovery_set: &[TokenKind]) {
+        if !self.is_at_end() {
+            self.advance();
         }
 
-        Ok(Program { items })
+        while !self.is_at_end() {
+            if recovery_set.iter().any(|kind| self.check(kind)) {
+                return;
+            }
+            self.advance();
+        }
     }
 
     fn parse_item(&mut self) -> Result<Item, CompilerError> {
@@ -49,6 +102,7 @@ impl Parser {
     }
 
     fn parse_world_decl(&mut self) -> Result<WorldDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::World, "Expected 'world'")?;
         self.consume(TokenKind::LeftBrace, "Expected '{' after 'world'")?;
 
@@ -61,15 +115,25 @@ impl Parser {
                 continue;
             }
 
-            rooms.push(self.parse_room_decl()?);
+            match self.parse_room_decl() {
+                Ok(room) => rooms.push(room),
+                Err(err) => {
+                    self.errors.push(err);
+                    self.synchronize(ROOM_RECOVERY_SET);
+                }
+            }
         }
 
         self.consume(TokenKind::RightBrace, "Expected '}' after world body")?;
 
-        Ok(WorldDecl { rooms })
+        Ok(WorldDecl {
+            rooms,
+            span: self.span_from(&start),
+        })
     }
 
     fn parse_room_decl(&mut self) -> Result<RoomDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Room, "Expected 'room'")?;
 
         let identifier = self.consume_identifier("Expected room identifier")?;
@@ -140,10 +204,12 @@ impl Parser {
             on_enter,
             on_exit,
             on_look,
+            span: self.span_from(&start),
         })
     }
 
     fn parse_object_decl(&mut self) -> Result<ObjectDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Object, "Expected 'object'")?;
 
         let identifier = self.consume_identifier("Expected object identifier")?;
@@ -151,7 +217,7 @@ impl Parser {
         self.consume(TokenKind::LeftBrace, "Expected '{' after object identifier")?;
 
         let mut names = Vec::new();
-        let mut description = String::new();
+        let mut description = Expr::String(String::new());
         let mut properties = HashMap::new();
         let mut contains = Vec::new();
 
@@ -171,7 +237,7 @@ impl Parser {
                 TokenKind::Desc => {
                     self.advance();
                     self.consume(TokenKind::Colon, "Expected ':' after 'desc'")?;
-                    description = self.parse_expression_as_string()?;
+                    description = self.parse_expression()?;
                 }
                 TokenKind::Contains => {
                     self.advance();
@@ -215,6 +281,7 @@ impl Parser {
             attributes: Vec::new(), // TODO: Parse from object syntax
             numbered_properties: HashMap::new(), // TODO: Parse from object syntax
             contains,
+            span: self.span_from(&start),
         })
     }
 
@@ -239,7 +306,9 @@ impl Parser {
             let expr = self.parse_expression()?;
             let target = match expr {
                 Expr::Identifier(room_name) => ExitTarget::Room(room_name),
-                Expr::FunctionCall { name, arguments } if name == "blocked" => {
+                Expr::FunctionCall {
+                    name, arguments, ..
+                } if name == "blocked" => {
                     if let Some(Expr::String(message)) = arguments.first() {
                         ExitTarget::Blocked(message.clone())
                     } else {
@@ -284,52 +353,40 @@ impl Parser {
         Ok(strings)
     }
 
+    /// Parse a `key: value` property value. `value` is any expression - a
+    /// literal, an identifier reference (e.g. `initial_location: west_house`),
+    /// an array literal, or a call - so computed defaults like `weight: 3 * 2`
+    /// parse too. Literals are folded into their matching `PropertyValue`
+    /// variant immediately; anything else is kept as `PropertyValue::Expr`
+    /// for the analyzer to check, `ast_optimizer` to constant-fold, and IR
+    /// generation to resolve identifier references to object numbers.
     fn parse_property_value(&mut self) -> Result<PropertyValue, CompilerError> {
-        match &self.peek().kind {
-            TokenKind::True => {
-                self.advance();
-                Ok(PropertyValue::Boolean(true))
-            }
-            TokenKind::False => {
-                self.advance();
-                Ok(PropertyValue::Boolean(false))
-            }
-            TokenKind::IntegerLiteral(val) => {
-                let value = *val;
-                self.advance();
-                Ok(PropertyValue::Integer(value))
-            }
-            TokenKind::StringLiteral(val) => {
-                let value = val.clone();
-                self.advance();
-                Ok(PropertyValue::String(value))
-            }
-            _ => {
-                // For now, treat everything else as an error
-                let token = self.peek();
-                Err(CompilerError::ExpectedToken(
-                    "boolean, integer, or string value".to_string(),
-                    format!("{:?}", token.kind),
-                    token.position,
-                ))
-            }
-        }
+        let expr = self.parse_expression()?;
+        Ok(Self::property_value_from_expr(expr))
     }
 
-    fn parse_expression_as_string(&mut self) -> Result<String, CompilerError> {
-        // Parse expression and convert to string representation
-        // This is a simplified approach - in a real compiler, we'd store the expression
-        let _expr = self.parse_expression()?;
-        // For now, return a placeholder string
-        // TODO: Properly evaluate string expressions during semantic analysis
-        Ok("[expression]".to_string())
+    fn property_value_from_expr(expr: Expr) -> PropertyValue {
+        match expr {
+            Expr::Boolean(val) => PropertyValue::Boolean(val),
+            Expr::Integer(val) => PropertyValue::Integer(val),
+            Expr::String(val) => PropertyValue::String(val),
+            Expr::Array(elements) => PropertyValue::Array(
+                elements
+                    .into_iter()
+                    .map(Self::property_value_from_expr)
+                    .collect(),
+            ),
+            other => PropertyValue::Expr(other),
+        }
     }
 
     fn parse_grammar_decl(&mut self) -> Result<GrammarDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Grammar, "Expected 'grammar'")?;
         self.consume(TokenKind::LeftBrace, "Expected '{' after 'grammar'")?;
 
         let mut verbs = Vec::new();
+        let mut separators = None;
 
         while !self.check(&TokenKind::RightBrace) && !self.is_at_end() {
             // Skip newlines
@@ -338,7 +395,20 @@ impl Parser {
                 continue;
             }
 
-            verbs.push(self.parse_verb_decl()?);
+            if matches!(&self.peek().kind, TokenKind::Identifier(key) if key == "separators") {
+                self.advance();
+                self.consume(TokenKind::Colon, "Expected ':' after 'separators'")?;
+                separators = Some(self.parse_separators_list()?);
+                continue;
+            }
+
+            match self.parse_verb_decl() {
+                Ok(verb) => verbs.push(verb),
+                Err(err) => {
+                    self.errors.push(err);
+                    self.synchronize(VERB_RECOVERY_SET);
+                }
+            }
         }
 
         self.consume(TokenKind::RightBrace, "Expected '}' after grammar body")?;
@@ -346,10 +416,32 @@ impl Parser {
         Ok(GrammarDecl {
             verbs,
             vocabulary: None, // TODO: Parse vocabulary declarations in future
+            separators,
+            span: self.span_from(&start),
         })
     }
 
+    /// Parse a `separators: [",", ".", "\""]` list: each element must be exactly one
+    /// character, matching what the dictionary header's separator byte can encode.
+    fn parse_separators_list(&mut self) -> Result<Vec<char>, CompilerError> {
+        let position = self.peek().position;
+        self.parse_string_array()?
+            .into_iter()
+            .map(|s| {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => Err(CompilerError::ParseError(
+                        format!("Expected single-character separator, found \"{s}\""),
+                        position,
+                    )),
+                }
+            })
+            .collect()
+    }
+
     fn parse_verb_decl(&mut self) -> Result<VerbDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Verb, "Expected 'verb'")?;
         let word = self.consume_string("Expected verb word")?;
 
@@ -369,7 +461,11 @@ impl Parser {
 
         self.consume(TokenKind::RightBrace, "Expected '}' after verb body")?;
 
-        Ok(VerbDecl { word, patterns })
+        Ok(VerbDecl {
+            word,
+            patterns,
+            span: self.span_from(&start),
+        })
     }
 
     fn parse_verb_pattern(&mut self) -> Result<VerbPattern, CompilerError> {
@@ -412,9 +508,9 @@ impl Parser {
             // Parse function call
             let expr = self.parse_expression()?;
             match expr {
-                Expr::FunctionCall { name, arguments } => {
-                    Ok(Handler::FunctionCall(name, arguments))
-                }
+                Expr::FunctionCall {
+                    name, arguments, ..
+                } => Ok(Handler::FunctionCall(name, arguments)),
                 _ => Err(CompilerError::ParseError(
                     "Handler must be function call or block".to_string(),
                     self.previous().position,
@@ -424,6 +520,7 @@ impl Parser {
     }
 
     fn parse_function_decl(&mut self) -> Result<FunctionDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Function, "Expected 'fn'")?;
         let name = self.consume_identifier("Expected function name")?;
 
@@ -479,6 +576,7 @@ impl Parser {
             parameters,
             return_type,
             body,
+            span: self.span_from(&start),
         })
     }
 
@@ -511,13 +609,18 @@ impl Parser {
     }
 
     fn parse_init_decl(&mut self) -> Result<InitDecl, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::Init, "Expected 'init'")?;
         let body = self.parse_block()?;
 
-        Ok(InitDecl { body })
+        Ok(InitDecl {
+            body,
+            span: self.span_from(&start),
+        })
     }
 
     fn parse_block(&mut self) -> Result<BlockStmt, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::LeftBrace, "Expected '{'")?;
 
         let mut statements = Vec::new();
@@ -534,7 +637,10 @@ impl Parser {
 
         self.consume(TokenKind::RightBrace, "Expected '}'")?;
 
-        Ok(BlockStmt { statements })
+        Ok(BlockStmt {
+            statements,
+            span: self.span_from(&start),
+        })
     }
 
     fn parse_statement(&mut self) -> Result<Stmt, CompilerError> {
@@ -548,6 +654,7 @@ impl Parser {
             TokenKind::LeftBrace => Ok(Stmt::Block(self.parse_block()?)),
             _ => {
                 // Check if this is an assignment or expression
+                let start = self.peek().clone();
                 let checkpoint = self.current;
 
                 // Try to parse as assignment
@@ -556,7 +663,11 @@ impl Parser {
                         self.advance(); // consume '='
                         let value = self.parse_expression()?;
                         self.consume_semicolon_optional();
-                        return Ok(Stmt::Assignment(AssignmentStmt { target, value }));
+                        return Ok(Stmt::Assignment(AssignmentStmt {
+                            target,
+                            value,
+                            span: self.span_from(&start),
+                        }));
                     }
                 }
 
@@ -570,6 +681,7 @@ impl Parser {
     }
 
     fn parse_var_decl(&mut self, mutable: bool) -> Result<Stmt, CompilerError> {
+        let start = self.peek().clone();
         if mutable {
             self.consume(TokenKind::Var, "Expected 'var'")?;
         } else {
@@ -599,10 +711,12 @@ impl Parser {
             mutable,
             var_type,
             initializer,
+            span: self.span_from(&start),
         }))
     }
 
     fn parse_if_stmt(&mut self) -> Result<Stmt, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::If, "Expected 'if'")?;
         let condition = self.parse_expression()?;
         let then_branch = Box::new(self.parse_statement()?);
@@ -618,18 +732,25 @@ impl Parser {
             condition,
             then_branch,
             else_branch,
+            span: self.span_from(&start),
         }))
     }
 
     fn parse_while_stmt(&mut self) -> Result<Stmt, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::While, "Expected 'while'")?;
         let condition = self.parse_expression()?;
         let body = Box::new(self.parse_statement()?);
 
-        Ok(Stmt::While(WhileStmt { condition, body }))
+        Ok(Stmt::While(WhileStmt {
+            condition,
+            body,
+            span: self.span_from(&start),
+        }))
     }
 
     fn parse_for_stmt(&mut self) -> Result<Stmt, CompilerError> {
+        let start = self.peek().clone();
         self.consume(TokenKind::For, "Expected 'for'")?;
         let variable = self.consume_identifier("Expected loop variable name")?;
         // Skip 'in' keyword - simplified for now
@@ -641,6 +762,7 @@ impl Parser {
             variable,
             iterable,
             body,
+            span: self.span_from(&start),
         }))
     }
 
@@ -685,6 +807,7 @@ impl Parser {
     }
 
     fn parse_logical_or(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_logical_and()?;
 
         while self.check(&TokenKind::Or) {
@@ -696,6 +819,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator: BinaryOp::Or,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -703,6 +827,7 @@ impl Parser {
     }
 
     fn parse_logical_and(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_equality()?;
 
         while self.check(&TokenKind::And) {
@@ -714,6 +839,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator: BinaryOp::And,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -721,6 +847,7 @@ impl Parser {
     }
 
     fn parse_equality(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_comparison()?;
 
         while self.match_token(&[TokenKind::EqualEqual, TokenKind::NotEqual]) {
@@ -734,6 +861,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -741,6 +869,7 @@ impl Parser {
     }
 
     fn parse_comparison(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_term()?;
 
         while self.match_token(&[
@@ -761,6 +890,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -768,6 +898,7 @@ impl Parser {
     }
 
     fn parse_term(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_factor()?;
 
         while self.match_token(&[TokenKind::Minus, TokenKind::Plus]) {
@@ -781,6 +912,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -788,6 +920,7 @@ impl Parser {
     }
 
     fn parse_factor(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_unary()?;
 
         while self.match_token(&[TokenKind::Slash, TokenKind::Star, TokenKind::Percent]) {
@@ -802,6 +935,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
+                span: self.span_from(&start),
             };
         }
 
@@ -809,6 +943,7 @@ impl Parser {
     }
 
     fn parse_unary(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         if self.match_token(&[TokenKind::Not, TokenKind::Minus]) {
             let operator = match self.previous().kind {
                 TokenKind::Not => UnaryOp::Not,
@@ -816,13 +951,18 @@ impl Parser {
                 _ => unreachable!(),
             };
             let operand = Box::new(self.parse_unary()?);
-            Ok(Expr::Unary { operator, operand })
+            Ok(Expr::Unary {
+                operator,
+                operand,
+                span: self.span_from(&start),
+            })
         } else {
             self.parse_call()
         }
     }
 
     fn parse_call(&mut self) -> Result<Expr, CompilerError> {
+        let start = self.peek().clone();
         let mut expr = self.parse_primary()?;
 
         loop {
@@ -843,7 +983,11 @@ impl Parser {
                 // Convert identifier or property access to function call
                 match expr {
                     Expr::Identifier(name) => {
-                        expr = Expr::FunctionCall { name, arguments };
+                        expr = Expr::FunctionCall {
+                            name,
+                            arguments,
+                            span: self.span_from(&start),
+                        };
                     }
                     Expr::PropertyAccess { object, property } => {
                         // Convert to method call with proper object context
@@ -958,6 +1102,16 @@ impl Parser {
         }
     }
 
+    /// Build the span covering everything consumed from `start` (the first
+    /// token of a production) through the most recently consumed token.
+    /// Tokens don't carry their own text length, so the end offset is
+    /// approximated as one past the last token's start - close enough to
+    /// anchor a caret, though not pixel-perfect for multi-character tokens.
+    fn span_from(&self, start: &Token) -> Span {
+        let end = (self.previous().position + 1).max(start.position + 1);
+        Span::new(&self.source, start.position, end)
+    }
+
     // Helper methods
     fn match_token(&mut self, types: &[TokenKind]) -> bool {
         for token_type in types {
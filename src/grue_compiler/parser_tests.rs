@@ -9,8 +9,10 @@ mod parser_tests {
     fn parse_input(input: &str) -> Result<Program, crate::grue_compiler::error::CompilerError> {
         let mut lexer = Lexer::new(input);
         let tokens = lexer.tokenize()?;
-        let mut parser = Parser::new(tokens);
-        parser.parse()
+        let mut parser = Parser::new(tokens, input);
+        parser
+            .parse()
+            .map_err(crate::grue_compiler::error::CompilerError::from)
     }
 
     #[test]
@@ -33,7 +35,9 @@ mod parser_tests {
             Item::Init(init_decl) => {
                 assert_eq!(init_decl.body.statements.len(), 1);
                 match &init_decl.body.statements[0] {
-                    Stmt::Expression(Expr::FunctionCall { name, arguments }) => {
+                    Stmt::Expression(Expr::FunctionCall {
+                        name, arguments, ..
+                    }) => {
                         assert_eq!(name, "print");
                         assert_eq!(arguments.len(), 1);
                         match &arguments[0] {
@@ -134,9 +138,7 @@ mod parser_tests {
                 let obj = &room.objects[0];
                 assert_eq!(obj.identifier, "mailbox");
                 assert_eq!(obj.names, vec!["small mailbox", "mailbox", "box"]);
-                // Note: Parser currently uses "[expression]" placeholder for property values
-                // Full property value parsing is not yet implemented (Oct 15, 2025)
-                assert!(obj.description == "[expression]" || obj.description == "A small mailbox.");
+                assert!(matches!(&obj.description, Expr::String(s) if s == "A small mailbox."));
 
                 assert_eq!(obj.properties.len(), 2);
                 match obj.properties.get("openable").unwrap() {
@@ -152,6 +154,49 @@ mod parser_tests {
         }
     }
 
+    #[test]
+    fn test_object_with_expression_valued_properties() {
+        let input = r#"
+            world {
+                room test_room "Test Room" {
+                    desc: "A room with an object."
+
+                    object lamp {
+                        names: ["brass lamp", "lamp"]
+                        desc: "A shiny brass lamp."
+                        weight: 3 * 2
+                        initial_location: west_house
+                        tags: ["shiny", "metal"]
+                    }
+                }
+            }
+        "#;
+        let program = parse_input(input).unwrap();
+
+        match &program.items[0] {
+            Item::World(world_decl) => {
+                let obj = &world_decl.rooms[0].objects[0];
+
+                assert!(matches!(
+                    obj.properties.get("weight"),
+                    Some(PropertyValue::Expr(Expr::Binary { .. }))
+                ));
+                assert!(matches!(
+                    obj.properties.get("initial_location"),
+                    Some(PropertyValue::Expr(Expr::Identifier(name))) if name == "west_house"
+                ));
+                match obj.properties.get("tags").unwrap() {
+                    PropertyValue::Array(elements) => {
+                        assert_eq!(elements.len(), 2);
+                        assert!(matches!(&elements[0], PropertyValue::String(s) if s == "shiny"));
+                    }
+                    other => panic!("expected an array property, got {:?}", other),
+                }
+            }
+            _ => panic!("Expected world declaration"),
+        }
+    }
+
     #[test]
     fn test_nested_objects() {
         let input = r#"
@@ -230,6 +275,40 @@ mod parser_tests {
         }
     }
 
+    #[test]
+    fn test_grammar_with_separators() {
+        let input = r#"
+            grammar {
+                separators: [",", ".", ";"]
+                verb "look" {
+                    default => look_around()
+                }
+            }
+        "#;
+        let program = parse_input(input).unwrap();
+
+        match &program.items[0] {
+            Item::Grammar(grammar_decl) => {
+                assert_eq!(grammar_decl.separators, Some(vec![',', '.', ';']));
+                assert_eq!(grammar_decl.verbs.len(), 1);
+            }
+            _ => panic!("Expected grammar declaration"),
+        }
+    }
+
+    #[test]
+    fn test_grammar_separators_rejects_multi_char_entry() {
+        let input = r#"
+            grammar {
+                separators: [",", "ab"]
+                verb "look" {
+                    default => look_around()
+                }
+            }
+        "#;
+        assert!(parse_input(input).is_err());
+    }
+
     #[test]
     fn test_grammar_with_parameters() {
         let input = r#"
@@ -580,7 +659,9 @@ mod parser_tests {
                 // Function call with multiple arguments
                 match &func_decl.body.statements[2] {
                     Stmt::Assignment(assign_stmt) => match &assign_stmt.value {
-                        Expr::FunctionCall { name, arguments } => {
+                        Expr::FunctionCall {
+                            name, arguments, ..
+                        } => {
                             assert_eq!(name, "calculate");
                             assert_eq!(arguments.len(), 3);
                         }
@@ -686,4 +767,20 @@ mod parser_tests {
         let result = parse_input(input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_dump_ast_snapshot() {
+        use crate::grue_compiler::dump_ast;
+
+        let program = parse_input("fn greet() { print(\"hi\"); }").unwrap();
+        let dump = dump_ast(&program);
+
+        // A full struct dump (including every Span) is too brittle to pin down
+        // verbatim, but it should read as a stable, greppable tree rather than
+        // a one-line Debug dump.
+        assert!(dump.contains("Function("));
+        assert!(dump.contains("name: \"greet\""));
+        assert!(dump.contains("FunctionCall"));
+        assert!(dump.contains("\"hi\""));
+    }
 }
@@ -79,118 +79,13 @@ impl SemanticAnalyzer {
     }
 
     fn add_builtin_functions(&mut self) {
-        // Add common built-in functions
-        let builtins = [
-            ("print", vec![Type::String], None),
-            ("print_num", vec![Type::Int], None),
-            ("print_ret", vec![Type::String], None),
-            ("new_line", vec![], None),
-            ("quit", vec![], None),
-            ("println", vec![Type::String], None),
-            ("error", vec![Type::String], None),
-            ("to_string", vec![Type::Any], Some(Type::String)),
-            ("to_int", vec![Type::String], Some(Type::Int)),
-            ("random", vec![Type::Int], Some(Type::Int)),
-            (
-                "length",
-                vec![Type::Array(Box::new(Type::Any))],
-                Some(Type::Int),
-            ),
-            (
-                "empty",
-                vec![Type::Array(Box::new(Type::Any))],
-                Some(Type::Bool),
-            ),
-            ("calculate", vec![Type::Int, Type::Int], Some(Type::Int)), // For test
-            ("process", vec![Type::Any], None),
-            ("update", vec![], None),
-            // Core builtin functions for object manipulation
-            // Note: Many functions are intentionally left as user-defined
-            // to allow games to customize their behavior (look_around, player_can_see, etc.)
-            ("move", vec![Type::Any, Type::Any], None),
-            // Score management functions for game writers
-            ("add_score", vec![Type::Int], None),
-            ("subtract_score", vec![Type::Int], None),
-            // Dictionary word conversion for numeric input
-            ("word_to_number", vec![Type::Any], Some(Type::Int)),
-            ("get_location", vec![Type::Any], Some(Type::Any)),
-            // Core Z-Machine object primitives - low-level operations only
-            ("get_child", vec![Type::Any], Some(Type::Any)),
-            ("get_sibling", vec![Type::Any], Some(Type::Any)),
-            ("get_prop", vec![Type::Any, Type::Int], Some(Type::Any)),
-            ("test_attr", vec![Type::Any, Type::Int], Some(Type::Bool)),
-            ("set_attr", vec![Type::Any, Type::Int], None),
-            ("clear_attr", vec![Type::Any, Type::Int], None),
-            // Navigation system - room exit resolution
-            ("get_exit", vec![Type::Any, Type::String], Some(Type::Any)),
-            // String utility functions - COMPILE-TIME ONLY
-            // These functions only work with string literals and compile-time constants.
-            // They cannot operate on runtime variables due to Z-Machine limitations.
-            // The Z-Machine has no opcodes for string manipulation - only text output.
-            ("indexOf", vec![Type::String, Type::String], Some(Type::Int)),
-            ("slice", vec![Type::String, Type::Int], Some(Type::String)),
-            (
-                "substring",
-                vec![Type::String, Type::Int, Type::Int],
-                Some(Type::String),
-            ),
-            ("toLowerCase", vec![Type::String], Some(Type::String)),
-            ("toUpperCase", vec![Type::String], Some(Type::String)),
-            ("trim", vec![Type::String], Some(Type::String)),
-            ("charAt", vec![Type::String, Type::Int], Some(Type::String)),
-            (
-                "split",
-                vec![Type::String, Type::String],
-                Some(Type::Array(Box::new(Type::String))),
-            ),
-            (
-                "replace",
-                vec![Type::String, Type::String, Type::String],
-                Some(Type::String),
-            ),
-            (
-                "startsWith",
-                vec![Type::String, Type::String],
-                Some(Type::Bool),
-            ),
-            (
-                "endsWith",
-                vec![Type::String, Type::String],
-                Some(Type::Bool),
-            ),
-            // Math utility functions
-            ("abs", vec![Type::Int], Some(Type::Int)),
-            ("min", vec![Type::Int, Type::Int], Some(Type::Int)),
-            ("max", vec![Type::Int, Type::Int], Some(Type::Int)),
-            ("round", vec![Type::Any], Some(Type::Int)),
-            ("floor", vec![Type::Any], Some(Type::Int)),
-            ("ceil", vec![Type::Any], Some(Type::Int)),
-            // Type checking functions
-            ("is_string", vec![Type::Any], Some(Type::Bool)),
-            ("is_int", vec![Type::Any], Some(Type::Bool)),
-            ("is_bool", vec![Type::Any], Some(Type::Bool)),
-            ("is_array", vec![Type::Any], Some(Type::Bool)),
-            ("is_object", vec![Type::Any], Some(Type::Bool)),
-            ("typeof", vec![Type::Any], Some(Type::String)),
-        ];
-
-        // Add debug_break builtin (debug builds only)
-        #[cfg(debug_assertions)]
-        let debug_builtins = [("debug_break", vec![Type::String], None)];
-
-        #[cfg(not(debug_assertions))]
-        let debug_builtins: [(&str, Vec<Type>, Option<Type>); 0] = [];
-
-        // Combine builtins
-        let all_builtins = builtins.iter().chain(debug_builtins.iter());
-
-        for (name, params, return_type) in all_builtins {
+        for (name, params, return_type) in builtin_function_signatures() {
             log::debug!("SEMANTIC: Registering builtin function: {}", name);
             let symbol = Symbol {
                 name: name.to_string(),
                 symbol_type: SymbolType::Function {
-                    params: params.clone(),
-                    return_type: return_type.clone(),
+                    params,
+                    return_type,
                 },
                 line: 0,
             };
@@ -217,7 +112,114 @@ impl SemanticAnalyzer {
             self.current_scope.symbols.insert(name.to_string(), symbol);
         }
     }
+}
+
+/// The name, parameter types, and return type of every builtin function the
+/// compiler recognizes. Shared with `analyzer::Analyzer` so the two passes
+/// agree on what counts as a defined function.
+pub(crate) fn builtin_function_signatures() -> Vec<(&'static str, Vec<Type>, Option<Type>)> {
+    let mut builtins: Vec<(&'static str, Vec<Type>, Option<Type>)> = vec![
+        ("print", vec![Type::String], None),
+        ("print_num", vec![Type::Int], None),
+        ("print_ret", vec![Type::String], None),
+        ("new_line", vec![], None),
+        ("quit", vec![], None),
+        ("println", vec![Type::String], None),
+        ("error", vec![Type::String], None),
+        ("to_string", vec![Type::Any], Some(Type::String)),
+        ("to_int", vec![Type::String], Some(Type::Int)),
+        ("random", vec![Type::Int], Some(Type::Int)),
+        (
+            "length",
+            vec![Type::Array(Box::new(Type::Any))],
+            Some(Type::Int),
+        ),
+        (
+            "empty",
+            vec![Type::Array(Box::new(Type::Any))],
+            Some(Type::Bool),
+        ),
+        ("calculate", vec![Type::Int, Type::Int], Some(Type::Int)), // For test
+        ("process", vec![Type::Any], None),
+        ("update", vec![], None),
+        // Core builtin functions for object manipulation
+        // Note: Many functions are intentionally left as user-defined
+        // to allow games to customize their behavior (look_around, player_can_see, etc.)
+        ("move", vec![Type::Any, Type::Any], None),
+        // Score management functions for game writers
+        ("add_score", vec![Type::Int], None),
+        ("subtract_score", vec![Type::Int], None),
+        // Dictionary word conversion for numeric input
+        ("word_to_number", vec![Type::Any], Some(Type::Int)),
+        ("get_location", vec![Type::Any], Some(Type::Any)),
+        // Core Z-Machine object primitives - low-level operations only
+        ("get_child", vec![Type::Any], Some(Type::Any)),
+        ("get_sibling", vec![Type::Any], Some(Type::Any)),
+        ("get_prop", vec![Type::Any, Type::Int], Some(Type::Any)),
+        ("test_attr", vec![Type::Any, Type::Int], Some(Type::Bool)),
+        ("set_attr", vec![Type::Any, Type::Int], None),
+        ("clear_attr", vec![Type::Any, Type::Int], None),
+        // Navigation system - room exit resolution
+        ("get_exit", vec![Type::Any, Type::String], Some(Type::Any)),
+        // String utility functions - COMPILE-TIME ONLY
+        // These functions only work with string literals and compile-time constants.
+        // They cannot operate on runtime variables due to Z-Machine limitations.
+        // The Z-Machine has no opcodes for string manipulation - only text output.
+        ("indexOf", vec![Type::String, Type::String], Some(Type::Int)),
+        ("slice", vec![Type::String, Type::Int], Some(Type::String)),
+        (
+            "substring",
+            vec![Type::String, Type::Int, Type::Int],
+            Some(Type::String),
+        ),
+        ("toLowerCase", vec![Type::String], Some(Type::String)),
+        ("toUpperCase", vec![Type::String], Some(Type::String)),
+        ("trim", vec![Type::String], Some(Type::String)),
+        ("charAt", vec![Type::String, Type::Int], Some(Type::String)),
+        (
+            "split",
+            vec![Type::String, Type::String],
+            Some(Type::Array(Box::new(Type::String))),
+        ),
+        (
+            "replace",
+            vec![Type::String, Type::String, Type::String],
+            Some(Type::String),
+        ),
+        (
+            "startsWith",
+            vec![Type::String, Type::String],
+            Some(Type::Bool),
+        ),
+        (
+            "endsWith",
+            vec![Type::String, Type::String],
+            Some(Type::Bool),
+        ),
+        // Math utility functions
+        ("abs", vec![Type::Int], Some(Type::Int)),
+        ("min", vec![Type::Int, Type::Int], Some(Type::Int)),
+        ("max", vec![Type::Int, Type::Int], Some(Type::Int)),
+        ("round", vec![Type::Any], Some(Type::Int)),
+        ("floor", vec![Type::Any], Some(Type::Int)),
+        ("ceil", vec![Type::Any], Some(Type::Int)),
+        // Type checking functions
+        ("is_string", vec![Type::Any], Some(Type::Bool)),
+        ("is_int", vec![Type::Any], Some(Type::Bool)),
+        ("is_bool", vec![Type::Any], Some(Type::Bool)),
+        ("is_array", vec![Type::Any], Some(Type::Bool)),
+        ("is_object", vec![Type::Any], Some(Type::Bool)),
+        ("typeof", vec![Type::Any], Some(Type::String)),
+    ];
+
+    // Add debug_break builtin (debug builds only)
+    #[cfg(debug_assertions)]
+    builtins.push(("debug_break", vec![Type::String], None));
+
+    builtins
+}
 
+impl SemanticAnalyzer {
     pub fn analyze(&mut self, mut program: Program) -> Result<Program, CompilerError> {
         // First pass: collect all global symbols (functions, rooms)
         self.collect_global_symbols(&program)?;
@@ -749,6 +751,7 @@ impl SemanticAnalyzer {
                 left,
                 operator,
                 right,
+                ..
             } => {
                 let left_type = self.analyze_expression(left)?;
                 let right_type = self.analyze_expression(right)?;
@@ -784,9 +787,12 @@ impl SemanticAnalyzer {
             Expr::Unary {
                 operator: _,
                 operand,
+                ..
             } => self.analyze_expression(operand),
 
-            Expr::FunctionCall { name, arguments } => {
+            Expr::FunctionCall {
+                name, arguments, ..
+            } => {
                 // First, analyze all arguments
                 let mut arg_types = Vec::new();
                 for arg in arguments.iter_mut() {
@@ -11,7 +11,7 @@ mod semantic_tests {
     fn analyze_input(input: &str) -> Result<Program, CompilerError> {
         let mut lexer = Lexer::new(input);
         let tokens = lexer.tokenize()?;
-        let mut parser = Parser::new(tokens);
+        let mut parser = Parser::new(tokens, input);
         let ast = parser.parse()?;
         let mut analyzer = SemanticAnalyzer::new();
         analyzer.analyze(ast)
@@ -6,6 +6,9 @@ use crate::util::get_mem_addr;
 
 pub struct Header {
     pub version: u8,
+    /// Flags 1 byte (offset 0x01). In V1-3 bit 1 (0x02) selects the status-line type
+    /// ("time" when set, "score" when clear); in V4+ the bits have unrelated meanings.
+    pub flags1: u8,
     pub release: u16,
     pub serial: String,
     pub base_high_mem: usize,
@@ -19,12 +22,16 @@ pub struct Header {
     pub dictionary: usize,
     pub object_table_addr: usize,
     pub global_variables: usize,
+    /// Routine offset (word at 0x28), used only by V6/7: a packed routine address
+    /// unpacks to `4 * packed + 8 * routine_offset` rather than just `4 * packed`.
+    pub routine_offset: usize,
 }
 
 impl Header {
     pub fn new(bytes: &[u8]) -> Header {
         Header {
             version: bytes[0],
+            flags1: bytes[1],
             release: (bytes[2] as u16) * 256 + (bytes[3] as u16),
             serial: {
                 let mut serial: String = String::from("");
@@ -44,8 +51,15 @@ impl Header {
             dictionary: get_mem_addr(bytes, 0x08).unwrap(),
             object_table_addr: get_mem_addr(bytes, 0x0A).unwrap(),
             global_variables: get_mem_addr(bytes, 0x0C).unwrap(),
+            routine_offset: get_mem_addr(bytes, 0x28).unwrap(),
         }
     }
+
+    /// Whether this is a V1-3 "time" game, whose status line shows a clock instead of
+    /// score/moves (Flags 1, bit 1). Always `false` for V4+, which don't use `show_status`.
+    pub fn is_time_game(&self) -> bool {
+        self.version <= 3 && (self.flags1 & 0x02) != 0
+    }
 }
 
 impl Display for Header {
@@ -3,18 +3,31 @@
 //! V3 games like Zork only use sread (line input) and don't have read_char.
 //! This makes the input model much simpler and more reliable.
 
+use crate::display_trait::ZMachineDisplay;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute,
+    terminal::{self, DisableLineWrap, EnableLineWrap},
+};
 use log::debug;
 use std::io;
+use std::time::{Duration, Instant};
 
 pub struct V3Input {
     /// Input buffer for building lines
     buffer: String,
+    /// Whether we're currently in raw mode (interactive timed reads)
+    in_raw_mode: bool,
+    /// Cursor position within `buffer` during interactive timed reads
+    cursor_pos: usize,
 }
 
 impl V3Input {
     pub fn new() -> Self {
         V3Input {
             buffer: String::new(),
+            in_raw_mode: false,
+            cursor_pos: 0,
         }
     }
 
@@ -68,17 +81,25 @@ impl V3Input {
         Ok(self.buffer.clone())
     }
 
-    /// Read line with timer support for V3 games
+    /// Read line with timer support for V3 games (sread with time/routine operands)
     ///
-    /// In V3, timers are simpler - they just fire once after input for turn counting
+    /// When stdin is a terminal and a timer is armed, this arms a wall-clock
+    /// deadline and polls for terminal events; every time the deadline
+    /// elapses without a completed line, `routine_addr` is invoked (via
+    /// `timer_callback`) and the deadline re-arms for the next interval. If
+    /// the routine returns non-zero the read aborts early, leaving whatever
+    /// was typed so far in the buffer. Piped/non-terminal input has no
+    /// wall-clock meaning, so it falls back to a single blocking read
+    /// followed by one callback invocation (for turn counting).
     pub fn read_line_with_timer<F>(
         &mut self,
         time_tenths: u16,
         routine_addr: u16,
         timer_callback: Option<F>,
+        display: &mut dyn ZMachineDisplay,
     ) -> Result<(String, bool), String>
     where
-        F: FnOnce() -> Result<bool, String>,
+        F: FnMut() -> Result<bool, String>,
     {
         debug!(
             "V3 input: reading line with timer ({}s, routine=0x{:04x})",
@@ -86,20 +107,173 @@ impl V3Input {
             routine_addr
         );
 
-        // For V3 games, we use a simplified approach:
-        // 1. Get input normally (blocking is fine for turn-based games)
+        if !atty::is(atty::Stream::Stdin) {
+            return self.read_line_piped_with_timer(time_tenths, routine_addr, timer_callback);
+        }
+
+        self.read_line_interactive_with_timer(time_tenths, routine_addr, timer_callback, display)
+    }
+
+    /// Piped/non-terminal fallback: one blocking read, one callback firing
+    /// afterward (for turn counting). There's no wall clock to arm against a
+    /// pipe, so this can't interrupt mid-read.
+    fn read_line_piped_with_timer<F>(
+        &mut self,
+        time_tenths: u16,
+        routine_addr: u16,
+        mut timer_callback: Option<F>,
+    ) -> Result<(String, bool), String>
+    where
+        F: FnMut() -> Result<bool, String>,
+    {
         let input = self.read_line()?;
 
-        // 2. After input, fire timer callback if present (for turn counting)
         if time_tenths > 0 && routine_addr > 0 {
-            if let Some(callback) = timer_callback {
-                debug!("V3 input: calling timer callback after input");
+            if let Some(ref mut callback) = timer_callback {
+                debug!("V3 input: calling timer callback after piped input");
                 let _result = callback()?;
-                // For V3 games, timer result doesn't affect input continuation
             }
         }
 
-        Ok((input, false)) // V3 timers don't terminate input
+        Ok((input, false))
+    }
+
+    /// Interactive terminal read: non-blocking, event-driven, with a
+    /// re-arming wall-clock deadline for the timer routine.
+    fn read_line_interactive_with_timer<F>(
+        &mut self,
+        time_tenths: u16,
+        routine_addr: u16,
+        mut timer_callback: Option<F>,
+        display: &mut dyn ZMachineDisplay,
+    ) -> Result<(String, bool), String>
+    where
+        F: FnMut() -> Result<bool, String>,
+    {
+        terminal::enable_raw_mode().map_err(|e| format!("Failed to enable raw mode: {e}"))?;
+        self.in_raw_mode = true;
+        execute!(io::stdout(), DisableLineWrap).ok();
+
+        // Crossterm's terminal event stream has only one consumer: while we're polling
+        // it here, the ratatui display thread must stay off it or it'll steal keystrokes
+        // (see `ZMachineDisplay::set_input_active`).
+        display.set_input_active(true).ok();
+
+        self.buffer.clear();
+        self.cursor_pos = 0;
+
+        let timeout = if time_tenths > 0 && routine_addr > 0 {
+            Some(Duration::from_millis((time_tenths as u64) * 100))
+        } else {
+            None
+        };
+        let mut start_time = Instant::now();
+
+        debug!(
+            "V3 input: interactive timed read, timeout={:?}, routine=0x{:04x}",
+            timeout, routine_addr
+        );
+
+        let result = loop {
+            if let Some(timeout_duration) = timeout {
+                if start_time.elapsed() >= timeout_duration {
+                    debug!("V3 input: timer expired after {:?}", start_time.elapsed());
+                    if let Some(ref mut callback) = timer_callback {
+                        match callback() {
+                            Ok(true) => {
+                                debug!("V3 input: timer callback requested termination");
+                                break Ok((self.buffer.clone(), true));
+                            }
+                            Ok(false) => {
+                                start_time = Instant::now(); // Re-arm for next interval
+                            }
+                            Err(e) => break Err(format!("Timer callback error: {e}")),
+                        }
+                    }
+                }
+            }
+
+            let poll_timeout = if timeout.is_some() {
+                Duration::from_millis(100)
+            } else {
+                Duration::from_secs(3600)
+            };
+
+            if event::poll(poll_timeout).map_err(|e| format!("Event poll error: {e}"))? {
+                match event::read().map_err(|e| format!("Event read error: {e}"))? {
+                    Event::Key(key_event) => {
+                        if let Some(line) = self.handle_key_event(key_event, display)? {
+                            break Ok((line, false));
+                        }
+                    }
+                    Event::Paste(text) => {
+                        for ch in text.chars() {
+                            self.buffer.insert(self.cursor_pos, ch);
+                            self.cursor_pos += 1;
+                        }
+                        display.print_input_echo(&text).ok();
+                    }
+                    _ => {
+                        // Ignore mouse/resize/focus events
+                    }
+                }
+            }
+        };
+
+        self.cleanup();
+        display.set_input_active(false).ok();
+        execute!(io::stdout(), EnableLineWrap).ok();
+
+        // Z-Machine spec 15.4 (read): if input ended the usual way (the
+        // player pressed return), a newline is printed so the cursor moves on.
+        if let Ok((_, false)) = &result {
+            display.print("\n").ok();
+        }
+
+        result
+    }
+
+    /// Handle one key event during an interactive timed line read.
+    fn handle_key_event(
+        &mut self,
+        key: KeyEvent,
+        display: &mut dyn ZMachineDisplay,
+    ) -> Result<Option<String>, String> {
+        match key.code {
+            KeyCode::Enter => Ok(Some(self.buffer.clone())),
+            KeyCode::Char(c) => {
+                if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
+                    return Err("Interrupted by Ctrl+C".to_string());
+                }
+                self.buffer.insert(self.cursor_pos, c);
+                self.cursor_pos += 1;
+                display.print_input_echo(&c.to_string()).ok();
+                Ok(None)
+            }
+            KeyCode::Backspace => {
+                if self.cursor_pos > 0 {
+                    self.cursor_pos -= 1;
+                    self.buffer.remove(self.cursor_pos);
+                    display.print_input_echo("\x08 \x08").ok();
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Ensure raw mode is left enabled only while an interactive read is in progress
+    fn cleanup(&mut self) {
+        if self.in_raw_mode {
+            let _ = terminal::disable_raw_mode();
+            self.in_raw_mode = false;
+        }
+    }
+}
+
+impl Drop for V3Input {
+    fn drop(&mut self) {
+        self.cleanup();
     }
 }
 
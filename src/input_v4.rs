@@ -37,6 +37,7 @@ impl V4Input {
         time_tenths: u16,
         _routine_addr: u16,
         timer_callback: Option<F>,
+        display: &mut dyn ZMachineDisplay,
     ) -> Result<(char, bool), String>
     where
         F: FnMut() -> Result<bool, String>,
@@ -56,7 +57,7 @@ impl V4Input {
 
         // Interactive terminal - use event-driven input
         debug!("V4 input: interactive mode, using terminal events");
-        self.read_char_interactive(time_tenths, _routine_addr, timer_callback)
+        self.read_char_interactive(time_tenths, _routine_addr, timer_callback, display)
     }
 
     /// Read a line for V4+ games (sread instruction)
@@ -135,6 +136,7 @@ impl V4Input {
         time_tenths: u16,
         _routine_addr: u16,
         mut timer_callback: Option<F>,
+        display: &mut dyn ZMachineDisplay,
     ) -> Result<(char, bool), String>
     where
         F: FnMut() -> Result<bool, String>,
@@ -145,6 +147,11 @@ impl V4Input {
         terminal::enable_raw_mode().map_err(|e| format!("Failed to enable raw mode: {e}"))?;
         self.in_raw_mode = true;
 
+        // Crossterm's terminal event stream has only one consumer: while we're polling
+        // it here, the ratatui display thread must stay off it or it'll steal keystrokes
+        // (see `ZMachineDisplay::set_input_active`).
+        display.set_input_active(true).ok();
+
         let timeout = if time_tenths > 0 {
             Some(Duration::from_millis((time_tenths as u64) * 100))
         } else {
@@ -195,6 +202,7 @@ impl V4Input {
 
         // Cleanup
         self.cleanup_raw_mode();
+        display.set_input_active(false).ok();
         result
     }
 
@@ -216,6 +224,11 @@ impl V4Input {
         self.in_raw_mode = true;
         execute!(io::stdout(), DisableLineWrap).ok();
 
+        // Crossterm's terminal event stream has only one consumer: while we're polling
+        // it here, the ratatui display thread must stay off it or it'll steal keystrokes
+        // (see `ZMachineDisplay::set_input_active`).
+        display.set_input_active(true).ok();
+
         // Clear line buffer
         self.line_buffer.clear();
         self.cursor_pos = 0;
@@ -281,8 +294,9 @@ impl V4Input {
 
         // Cleanup
         self.cleanup_raw_mode();
+        display.set_input_active(false).ok();
         execute!(io::stdout(), EnableLineWrap).ok();
-        
+
         // Z-Machine spec 15.4 (read): "If input was terminated in the usual way, by the player 
         // typing a carriage return, then a carriage return is printed (so the cursor moves to the next line)"
         if let Ok((_, false)) = &result {
@@ -5,6 +5,8 @@ use crate::input_v3::V3Input;
 use crate::input_v4::V4Input;
 use crate::instruction::{Instruction, OperandType};
 use crate::text;
+use crate::trace::{TraceRecord, Tracer};
+use crate::trap::{Trap, TrapAction};
 use crate::vm::{CallFrame, VM};
 use log::{debug, info};
 use std::io::{self, Write};
@@ -50,6 +52,10 @@ pub struct Interpreter {
     pub(crate) display: Option<Box<dyn ZMachineDisplay>>,
     /// Output stream state
     output_streams: OutputStreamState,
+    /// Optional structured execution tracer (see [`crate::trace`])
+    tracer: Option<Tracer>,
+    /// Optional handler for classified faults (see [`crate::trap`])
+    trap_handler: Option<Box<dyn FnMut(&Trap) -> TrapAction>>,
 }
 
 /// State for managing output stream redirection
@@ -110,6 +116,8 @@ impl Interpreter {
             v4_input,
             display,
             output_streams: OutputStreamState::new(),
+            tracer: None,
+            trap_handler: None,
         }
     }
 
@@ -118,6 +126,67 @@ impl Interpreter {
         self.debug = debug;
     }
 
+    /// Install a structured execution tracer (replacing any previous one).
+    ///
+    /// Once installed, every instruction executed through [`Interpreter::run_with_limit`]
+    /// is turned into a [`TraceRecord`] and offered to the tracer's filter/sink.
+    pub fn set_tracer(&mut self, tracer: Tracer) {
+        self.tracer = Some(tracer);
+    }
+
+    /// Remove any installed tracer.
+    pub fn clear_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Turn `inst`, decoded at `pc`, into a [`TraceRecord`] and offer it to the
+    /// installed tracer, if any. Shared by [`Interpreter::run_with_limit`] and
+    /// [`crate::debugger::Debugger::step`] so the ring buffer behind the REPL's
+    /// `trace`/`dump_trace` commands is populated by interactive single-stepping too,
+    /// not just free-running execution.
+    pub(crate) fn offer_trace(&mut self, pc: u32, inst: &Instruction) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            let record = TraceRecord::new(
+                self.instruction_count,
+                pc,
+                inst,
+                self.vm.game.header.version,
+                self.vm.stack.len(),
+                self.vm.call_stack.len(),
+            );
+            tracer.offer(record);
+        }
+    }
+
+    /// Install a handler for classified faults (replacing any previous one).
+    ///
+    /// Decode and execution faults hit during [`Interpreter::run_with_limit`]
+    /// are classified into a [`Trap`] via [`Trap::classify`] and offered to
+    /// this handler, which decides whether the fault should abort the run,
+    /// be ignored, or resume with a substitute value (see [`TrapAction`]).
+    /// With no handler installed, every fault aborts, matching this
+    /// interpreter's historical behavior.
+    pub fn set_trap_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(&Trap) -> TrapAction + 'static,
+    {
+        self.trap_handler = Some(Box::new(handler));
+    }
+
+    /// Remove any installed trap handler.
+    pub fn clear_trap_handler(&mut self) {
+        self.trap_handler = None;
+    }
+
+    /// Offer a classified fault to the installed trap handler, defaulting to
+    /// [`TrapAction::Abort`] when none is installed.
+    fn dispatch_trap(&mut self, trap: &Trap) -> TrapAction {
+        match self.trap_handler.as_mut() {
+            Some(handler) => handler(trap),
+            None => TrapAction::Abort,
+        }
+    }
+
     /// Enable single-step debugging for a PC range
     pub fn enable_single_step(&mut self, start: u32, end: u32) {
         self.single_step = true;
@@ -381,10 +450,22 @@ impl Interpreter {
             ) {
                 Ok(inst) => inst,
                 Err(e) => {
-                    return Err(format!("Failed to decode instruction at {pc:05x}: {e}"));
+                    let trap = Trap::classify(&e);
+                    match self.dispatch_trap(&trap) {
+                        TrapAction::Abort => {
+                            return Err(format!("Failed to decode instruction at {pc:05x}: {e}"));
+                        }
+                        TrapAction::Continue | TrapAction::ResumeWith(_) => {
+                            debug!("Trap handler recovered from decode fault at {pc:05x}: {trap}");
+                            self.vm.pc += 1;
+                            continue;
+                        }
+                    }
                 }
             };
 
+            self.offer_trace(pc, &instruction);
+
             // Check if we should single-step this instruction
             let should_step = self.single_step
                 && match self.step_range {
@@ -507,7 +588,32 @@ impl Interpreter {
             let pc_before_exec = self.vm.pc;
 
             // Execute the instruction
-            match self.execute_instruction(&instruction)? {
+            let exec_result = match self.execute_instruction(&instruction) {
+                Ok(result) => result,
+                Err(e) => {
+                    let trap = Trap::classify(&e);
+                    match self.dispatch_trap(&trap) {
+                        TrapAction::Abort => return Err(e),
+                        TrapAction::Continue => {
+                            debug!(
+                                "Trap handler recovered from execution fault at {pc:05x}: {trap}"
+                            );
+                            continue;
+                        }
+                        TrapAction::ResumeWith(value) => {
+                            debug!(
+                                "Trap handler resuming execution fault at {pc:05x} with {value}: {trap}"
+                            );
+                            if let Some(store_var) = instruction.store_var {
+                                self.vm.write_variable(store_var, value)?;
+                            }
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            match exec_result {
                 ExecutionResult::Continue => {
                     // Normal execution, PC already advanced
                     // Debug PC state after execution for Trinity tracking
@@ -1231,8 +1337,13 @@ impl Interpreter {
                     // In v3: G16 = player location, G17 = score, G18 = moves
                     let location_obj = self.vm.read_global(16)?;
                     let location_name = self.get_object_name(location_obj)?;
-                    let score = self.vm.read_global(17)? as i16;
-                    let moves = self.vm.read_global(18)?;
+                    let a = self.vm.read_global(17)? as i16;
+                    let b = self.vm.read_global(18)?;
+                    let mode = if self.vm.game.header.is_time_game() {
+                        crate::display_trait::StatusLineMode::Time
+                    } else {
+                        crate::display_trait::StatusLineMode::Score
+                    };
 
                     // Now update display
                     if let Some(ref mut display) = self.display {
@@ -1240,11 +1351,11 @@ impl Interpreter {
                         display.split_window(1)?;
 
                         // Update status line with version info
-                        display.show_status(&location_name, score, moves)?;
+                        display.show_status(&location_name, mode, a, b)?;
 
                         debug!(
-                            "Auto-updated status line: location='{}', score={}, moves={}",
-                            location_name, score, moves
+                            "Auto-updated status line: location='{}', mode={:?}, a={}, b={}",
+                            location_name, mode, a, b
                         );
                     }
                 }
@@ -1290,9 +1401,18 @@ impl Interpreter {
                     // V3 and earlier - use simple input handler
                     debug!("Using V3 input handler for sread");
                     if let Some(ref mut v3_input) = self.v3_input {
-                        v3_input
-                            .read_line_with_timer(time, routine, timer_callback)
-                            .map_err(|e| format!("Error reading V3 input: {e}"))?
+                        if let Some(ref mut display) = self.display {
+                            v3_input
+                                .read_line_with_timer(
+                                    time,
+                                    routine,
+                                    timer_callback,
+                                    display.as_mut(),
+                                )
+                                .map_err(|e| format!("Error reading V3 input: {e}"))?
+                        } else {
+                            return Err("Display not initialized for V3 input".to_string());
+                        }
                     } else {
                         return Err("V3 input handler not initialized".to_string());
                     }
@@ -1570,9 +1690,13 @@ impl Interpreter {
                 // Use V4+ input handler for character input
                 let (ch, was_terminated) = if let Some(ref mut v4_input) = self.v4_input {
                     debug!("Using V4+ input handler for read_char");
-                    v4_input
-                        .read_char(time, routine, timer_callback)
-                        .map_err(|e| format!("Error reading V4+ character: {e}"))?
+                    if let Some(ref mut display) = self.display {
+                        v4_input
+                            .read_char(time, routine, timer_callback, display.as_mut())
+                            .map_err(|e| format!("Error reading V4+ character: {e}"))?
+                    } else {
+                        return Err("Display not initialized for read_char".to_string());
+                    }
                 } else {
                     return Err("V4+ input handler not initialized for read_char".to_string());
                 };
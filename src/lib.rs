@@ -25,8 +25,11 @@ pub mod opcode_tables;
 pub mod property_defaults;
 pub mod quetzal;
 pub mod routine;
+pub mod symbols;
 pub mod text;
 pub mod timed_input;
+pub mod trace;
+pub mod trap;
 pub mod util;
 pub mod vm;
 pub mod zobject;
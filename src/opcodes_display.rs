@@ -83,11 +83,17 @@ impl Interpreter {
                         "Unknown".to_string()
                     };
 
-                    let score = self.vm.read_global(17)? as i16; // G17 = score
-                    let moves = self.vm.read_global(18)?; // G18 = moves
+                    // G17/G18 double as score/moves or, for "time" games, hours/minutes
+                    let a = self.vm.read_global(17)? as i16;
+                    let b = self.vm.read_global(18)?;
+                    let mode = if self.vm.game.header.is_time_game() {
+                        crate::display_trait::StatusLineMode::Time
+                    } else {
+                        crate::display_trait::StatusLineMode::Score
+                    };
 
                     if let Some(ref mut display) = self.display {
-                        display.show_status(&location_name, score, moves)?;
+                        display.show_status(&location_name, mode, a, b)?;
                     } else {
                         debug!("No display available for show_status");
                     }
@@ -346,6 +352,25 @@ impl Interpreter {
                 Ok(ExecutionResult::Continue)
             }
 
+            // 2OP/VAR:0x1B - set_colour (true-VAR 0x1B is tokenise, not handled here)
+            (0x1B, crate::instruction::OperandCount::OP2) => {
+                if operands.len() >= 2 {
+                    let foreground = operands[0];
+                    let background = operands[1];
+                    debug!(
+                        "set_colour: foreground={}, background={}",
+                        foreground, background
+                    );
+
+                    if let Some(ref mut display) = self.display {
+                        display.set_colour(foreground, background).ok();
+                    }
+                } else {
+                    debug!("set_colour called with insufficient operands");
+                }
+                Ok(ExecutionResult::Continue)
+            }
+
             // VAR:0x15 - sound_effect
             (0x15, crate::instruction::OperandCount::VAR) => {
                 if !operands.is_empty() {
@@ -417,6 +442,7 @@ impl Interpreter {
             (0x10, crate::instruction::OperandCount::VAR) |  // get_cursor
             (0x0F, crate::instruction::OperandCount::VAR) |  // set_cursor
             (0x11, crate::instruction::OperandCount::VAR) |  // set_text_style
+            (0x1B, crate::instruction::OperandCount::OP2) |  // set_colour
             (0x12, crate::instruction::OperandCount::VAR) |  // buffer_mode
             (0x15, crate::instruction::OperandCount::VAR) // sound_effect
         )
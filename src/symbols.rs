@@ -0,0 +1,309 @@
+//! Symbol map: human-readable names for routines, objects, and branch/jump labels.
+//!
+//! Names can be supplied by a user (`STAND`, `LAMP`) or auto-derived (object short
+//! names from the object table's Z-string, via [`SymbolTable::auto_name_objects`]), and
+//! persist across sessions as a simple `addr name kind` text file. Disassembly callers
+//! consult the table via [`format_instruction_with_symbols`] so calls, object operands,
+//! and branch targets render as names instead of bare hex when one is known.
+
+use crate::disassembler::unpack_routine_address;
+use crate::header::Header;
+use crate::instruction::{Instruction, OperandType};
+use crate::vm::VM;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+
+/// What kind of thing a symbol names, and therefore how its address is interpreted:
+/// a byte address for routines and labels, an object number for objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymbolKind {
+    Routine,
+    Object,
+    Label,
+}
+
+impl SymbolKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SymbolKind::Routine => "routine",
+            SymbolKind::Object => "object",
+            SymbolKind::Label => "label",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "routine" => Some(SymbolKind::Routine),
+            "object" => Some(SymbolKind::Object),
+            "label" => Some(SymbolKind::Label),
+            _ => None,
+        }
+    }
+}
+
+/// A name table for routines, objects, and labels, loadable from and savable to a
+/// text map file.
+#[derive(Debug, Default)]
+pub struct SymbolTable {
+    routines: HashMap<u32, String>,
+    objects: HashMap<u16, String>,
+    labels: HashMap<u32, String>,
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        SymbolTable::default()
+    }
+
+    pub fn name_routine(&mut self, addr: u32, name: impl Into<String>) {
+        self.routines.insert(addr, name.into());
+    }
+
+    pub fn name_object(&mut self, obj_num: u16, name: impl Into<String>) {
+        self.objects.insert(obj_num, name.into());
+    }
+
+    pub fn name_label(&mut self, addr: u32, name: impl Into<String>) {
+        self.labels.insert(addr, name.into());
+    }
+
+    pub fn routine_name(&self, addr: u32) -> Option<&str> {
+        self.routines.get(&addr).map(String::as_str)
+    }
+
+    pub fn object_name(&self, obj_num: u16) -> Option<&str> {
+        self.objects.get(&obj_num).map(String::as_str)
+    }
+
+    pub fn label_name(&self, addr: u32) -> Option<&str> {
+        self.labels.get(&addr).map(String::as_str)
+    }
+
+    /// Auto-name every object from its object-table short name, leaving any object
+    /// that already has a user-supplied name untouched.
+    pub fn auto_name_objects(&mut self, vm: &VM) {
+        let max_objects: u16 = if vm.game.header.version <= 3 {
+            255
+        } else {
+            65535
+        };
+        for obj_num in 1..=max_objects {
+            if self.objects.contains_key(&obj_num) {
+                continue;
+            }
+            if let Ok(name) = vm.get_object_name(obj_num) {
+                if !name.is_empty() {
+                    self.objects.insert(obj_num, name);
+                }
+            }
+        }
+    }
+
+    /// Load a symbol map from a text file of `addr name kind` lines, one symbol per
+    /// line (`kind` is `routine`, `object`, or `label`; for `object` lines `addr` is the
+    /// object number rather than a byte address). Blank lines and lines starting with
+    /// `#` are ignored.
+    pub fn load_from_file(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read symbol map {path}: {e}"))?;
+        let mut table = SymbolTable::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // Split off `addr` from the front and `kind` from the back rather than
+            // `splitn(3, ' ')`, since `name` itself can contain spaces (auto-named
+            // objects come straight from the game's own multi-word short names, e.g.
+            // "brass lantern") and a naive 3-way split would cut `name` short.
+            let (addr_str, rest) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("{path}:{}: expected 'addr name kind'", i + 1))?;
+            let (name, kind_str) = rest
+                .rsplit_once(' ')
+                .ok_or_else(|| format!("{path}:{}: expected 'addr name kind'", i + 1))?;
+            let addr = parse_addr(addr_str).map_err(|e| format!("{path}:{}: {e}", i + 1))?;
+            let kind = SymbolKind::from_str(kind_str)
+                .ok_or_else(|| format!("{path}:{}: unknown symbol kind '{kind_str}'", i + 1))?;
+
+            match kind {
+                SymbolKind::Routine => table.name_routine(addr, name),
+                SymbolKind::Object => table.name_object(addr as u16, name),
+                SymbolKind::Label => table.name_label(addr, name),
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Save this symbol map to a text file of `addr name kind` lines, sorted by kind
+    /// then address so re-saving an unchanged table produces an unchanged file.
+    pub fn save_to_file(&self, path: &str) -> Result<(), String> {
+        let mut out = String::new();
+
+        let mut routines: Vec<_> = self.routines.iter().collect();
+        routines.sort_by_key(|(addr, _)| **addr);
+        for (addr, name) in routines {
+            write_symbol_line(&mut out, *addr, name, SymbolKind::Routine);
+        }
+
+        let mut objects: Vec<_> = self.objects.iter().collect();
+        objects.sort_by_key(|(num, _)| **num);
+        for (num, name) in objects {
+            write_symbol_line(&mut out, *num as u32, name, SymbolKind::Object);
+        }
+
+        let mut labels: Vec<_> = self.labels.iter().collect();
+        labels.sort_by_key(|(addr, _)| **addr);
+        for (addr, name) in labels {
+            write_symbol_line(&mut out, *addr, name, SymbolKind::Label);
+        }
+
+        fs::write(path, out).map_err(|e| format!("Failed to write symbol map {path}: {e}"))
+    }
+}
+
+fn write_symbol_line(out: &mut String, addr: u32, name: &str, kind: SymbolKind) {
+    writeln!(out, "{:#06x} {} {}", addr, name, kind.as_str()).unwrap();
+}
+
+fn parse_addr(s: &str) -> Result<u32, String> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    u32::from_str_radix(digits, 16).map_err(|_| format!("Invalid hex address: {s}"))
+}
+
+/// The operand indices (0-based) that `mnemonic` takes as object numbers rather than
+/// plain values, so [`format_instruction_with_symbols`] knows which operands to look up
+/// in the symbol table's object names.
+fn object_operand_indices(mnemonic: &str) -> &'static [usize] {
+    match mnemonic {
+        "get_parent" | "get_sibling" | "get_child" | "print_obj" | "remove_obj" | "test_attr"
+        | "set_attr" | "clear_attr" | "get_prop" | "get_prop_addr" | "get_next_prop" => &[0],
+        "jin" | "insert_obj" => &[0, 1],
+        _ => &[],
+    }
+}
+
+/// Format `inst` the way [`Instruction::format_with_version`] does, but render call
+/// targets, object operands, and branch targets as names when `symbols` has one for
+/// them, falling back to the normal hex rendering otherwise.
+pub fn format_instruction_with_symbols(
+    pc: u32,
+    inst: &Instruction,
+    version: u8,
+    header: &Header,
+    symbols: &SymbolTable,
+) -> String {
+    let mnemonic = inst.name(version);
+    let mut result = String::from(mnemonic);
+    let object_operands = object_operand_indices(mnemonic);
+    let is_call = mnemonic.starts_with("call");
+
+    for (i, op) in inst.operands.iter().enumerate() {
+        result.push_str(if i == 0 { " " } else { ", " });
+
+        let symbol = if i == 0 && is_call && *op != 0 {
+            let target = unpack_routine_address(*op, version, header);
+            symbols.routine_name(target)
+        } else if object_operands.contains(&i) {
+            symbols.object_name(*op)
+        } else {
+            None
+        };
+
+        match symbol {
+            Some(name) => result.push_str(name),
+            None => match inst.operand_types[i] {
+                OperandType::Variable => write!(result, "V{op:02x}").unwrap(),
+                _ => write!(result, "#{op:04x}").unwrap(),
+            },
+        }
+    }
+
+    if let Some(var) = inst.store_var {
+        write!(result, " -> V{var:02x}").unwrap();
+    }
+
+    if let Some(ref branch) = inst.branch {
+        let target_label = if branch.offset != 0 && branch.offset != 1 {
+            let target = (pc as i64 + inst.size as i64 + branch.offset as i64 - 2) as u32;
+            symbols.label_name(target).map(|s| s.to_string())
+        } else {
+            None
+        };
+        let suffix = match target_label {
+            Some(name) => format!(" {name}"),
+            None => match branch.offset {
+                0 => " RFALSE".to_string(),
+                1 => " RTRUE".to_string(),
+                n => format!(" {n:+}"),
+            },
+        };
+        write!(
+            result,
+            " [{}{suffix}]",
+            if branch.on_true { "TRUE" } else { "FALSE" }
+        )
+        .unwrap();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("gruesome_symbols_test_{name}"))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn round_trips_multi_word_object_names() {
+        let path = temp_path("multiword");
+        let mut table = SymbolTable::new();
+        table.name_object(4, "brass lantern");
+        table.name_routine(0x4e20, "STAND");
+        table.name_label(0x1234, "done");
+        table.save_to_file(&path).unwrap();
+
+        let loaded = SymbolTable::load_from_file(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.object_name(4), Some("brass lantern"));
+        assert_eq!(loaded.routine_name(0x4e20), Some("STAND"));
+        assert_eq!(loaded.label_name(0x1234), Some("done"));
+    }
+
+    #[test]
+    fn load_rejects_unknown_kind() {
+        let path = temp_path("badkind");
+        fs::write(&path, "0x0004 lamp widget\n").unwrap();
+
+        let result = SymbolTable::load_from_file(&path);
+        fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn load_skips_blank_and_comment_lines() {
+        let path = temp_path("comments");
+        fs::write(&path, "# a comment\n\n0x0001 LOOK routine\n").unwrap();
+
+        let table = SymbolTable::load_from_file(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(table.routine_name(0x0001), Some("LOOK"));
+    }
+}
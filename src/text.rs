@@ -217,6 +217,54 @@ pub fn decode_string_at_packed_addr(
     Ok(string)
 }
 
+/// Heuristically scan `memory[start..end]` for valid, terminated Z-strings, returning
+/// `(address, decoded)` pairs with abbreviations fully expanded. This is the `strings`-
+/// style replacement for hand-rolled "search a byte range for an opcode, then grep the
+/// decode for a known word" loops: every word-aligned address in range is tried, and a
+/// candidate is kept only if it decodes cleanly and its text looks like real prose
+/// rather than incidentally-valid-looking game data.
+///
+/// A successful match advances past the decoded string's length (Z-strings are always
+/// word-aligned and self-terminating), so overlapping false positives within the same
+/// run of text aren't also reported.
+pub fn scan_strings(
+    memory: &[u8],
+    abbrev_table_addr: usize,
+    start: usize,
+    end: usize,
+) -> Vec<(usize, String)> {
+    let mut found = Vec::new();
+    let mut addr = start & !1;
+    let end = end.min(memory.len());
+
+    while addr + 1 < end {
+        match decode_string(memory, addr, abbrev_table_addr) {
+            Ok((text, len)) if len > 0 && is_plausible_string(&text) => {
+                found.push((addr, text));
+                addr += len;
+            }
+            _ => addr += 2,
+        }
+    }
+
+    found
+}
+
+/// Reject decodes that are empty, implausibly long, or mostly non-printable — the
+/// telltale sign of having decoded ordinary game data (code, tables) rather than a
+/// genuine string.
+fn is_plausible_string(text: &str) -> bool {
+    if text.is_empty() || text.len() > 500 || text.trim().is_empty() {
+        return false;
+    }
+    let total = text.chars().count();
+    let printable = text
+        .chars()
+        .filter(|c| c.is_ascii_graphic() || *c == ' ' || *c == '\n')
+        .count();
+    printable * 10 >= total * 9
+}
+
 /// Unpack a string address based on version
 fn unpack_string_address(packed: u16, version: u8) -> usize {
     match version {
@@ -269,4 +317,27 @@ mod tests {
         assert_eq!(result, "a b");
         assert_eq!(len, 2);
     }
+
+    #[test]
+    fn test_scan_strings_finds_known_string() {
+        // Same "hello" encoding as test_simple_string, surrounded by non-string bytes.
+        let mut memory = vec![0u8; 100];
+        memory[10] = 0x72;
+        memory[11] = 0xE4;
+        memory[12] = 0x95;
+        memory[13] = 0x45;
+
+        let found = scan_strings(&memory, 0, 10, memory.len());
+        assert!(found
+            .iter()
+            .any(|(addr, text)| *addr == 10 && text == "hello"));
+    }
+
+    #[test]
+    fn test_scan_strings_skips_all_zero_region() {
+        // An all-zero region decodes as a run of spaces, which isn't a real string.
+        let memory = vec![0u8; 40];
+        let found = scan_strings(&memory, 0, 0, memory.len());
+        assert!(found.is_empty());
+    }
 }
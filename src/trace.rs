@@ -0,0 +1,209 @@
+//! Structured execution tracing for `Interpreter`/`Debugger`.
+//!
+//! Every ad-hoc debug binary in this crate has historically reimplemented its own
+//! filtered logging ("log after count > 1000", "every 100th instruction", ...).
+//! This module gives those tools (and the interactive debugger) a single, composable
+//! facility: each executed instruction is turned into a [`TraceRecord`] and handed to
+//! a [`TraceSink`], gated by a [`TraceFilter`].
+
+use crate::instruction::Instruction;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// A single executed-instruction record.
+#[derive(Debug, Clone)]
+pub struct TraceRecord {
+    /// Monotonic instruction count at the time this instruction executed.
+    pub count: u64,
+    /// Address the instruction was decoded from.
+    pub pc: u32,
+    /// Decoded instruction name (e.g. "je", "call_vs").
+    pub name: String,
+    /// Formatted operands, as rendered for disassembly.
+    pub operands: String,
+    /// Current evaluation-stack depth.
+    pub stack_depth: usize,
+    /// Current call-stack depth (routine nesting).
+    pub call_depth: usize,
+}
+
+impl TraceRecord {
+    /// Build a trace record for `inst` about to execute at `pc`.
+    pub fn new(
+        count: u64,
+        pc: u32,
+        inst: &Instruction,
+        version: u8,
+        stack_depth: usize,
+        call_depth: usize,
+    ) -> Self {
+        let name = inst.name(version).to_string();
+        let operands = inst
+            .operands
+            .iter()
+            .map(|v| format!("{v:04x}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        TraceRecord {
+            count,
+            pc,
+            name,
+            operands,
+            stack_depth,
+            call_depth,
+        }
+    }
+}
+
+impl std::fmt::Display for TraceRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{:<8} {:05x}: {:<12} [{}] (stack={} call={})",
+            self.count, self.pc, self.name, self.operands, self.stack_depth, self.call_depth
+        )
+    }
+}
+
+/// A composable predicate deciding whether a [`TraceRecord`] should reach a sink.
+///
+/// Filters combine with [`TraceFilter::and`]/[`TraceFilter::or`] so callers can build
+/// up expressions like "opcode prefix `call` AND pc in 0x4000..0x5000".
+pub enum TraceFilter {
+    /// Accept every record.
+    All,
+    /// Accept only records whose instruction name starts with this prefix.
+    NamePrefix(String),
+    /// Accept only records whose PC falls within this inclusive range.
+    PcRange(u32, u32),
+    /// Accept only records whose call-stack depth is at least this value.
+    MinCallDepth(usize),
+    /// Accept every Nth record (sampling), based on `count`.
+    SampleEvery(u64),
+    And(Box<TraceFilter>, Box<TraceFilter>),
+    Or(Box<TraceFilter>, Box<TraceFilter>),
+    Not(Box<TraceFilter>),
+}
+
+impl TraceFilter {
+    /// Combine two filters with logical AND.
+    pub fn and(self, other: TraceFilter) -> TraceFilter {
+        TraceFilter::And(Box::new(self), Box::new(other))
+    }
+
+    /// Combine two filters with logical OR.
+    pub fn or(self, other: TraceFilter) -> TraceFilter {
+        TraceFilter::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Negate a filter.
+    pub fn negate(self) -> TraceFilter {
+        TraceFilter::Not(Box::new(self))
+    }
+
+    /// Evaluate this filter against a record.
+    pub fn matches(&self, record: &TraceRecord) -> bool {
+        match self {
+            TraceFilter::All => true,
+            TraceFilter::NamePrefix(prefix) => record.name.starts_with(prefix.as_str()),
+            TraceFilter::PcRange(lo, hi) => record.pc >= *lo && record.pc <= *hi,
+            TraceFilter::MinCallDepth(min) => record.call_depth >= *min,
+            TraceFilter::SampleEvery(n) => *n > 0 && record.count % *n == 0,
+            TraceFilter::And(a, b) => a.matches(record) && b.matches(record),
+            TraceFilter::Or(a, b) => a.matches(record) || b.matches(record),
+            TraceFilter::Not(inner) => !inner.matches(record),
+        }
+    }
+}
+
+/// Destination for trace records accepted by a [`TraceFilter`].
+pub trait TraceSink {
+    /// Called once per record that passed the filter.
+    fn record(&mut self, record: TraceRecord);
+}
+
+/// Sink that prints each accepted record to stdout.
+pub struct PrintSink;
+
+impl TraceSink for PrintSink {
+    fn record(&mut self, record: TraceRecord) {
+        println!("{record}");
+    }
+}
+
+/// Sink that retains only the most recent `N` accepted records, discarding older ones.
+///
+/// Replaces the manual `last_10_pcs`-style vectors scattered across the debug binaries:
+/// when a breakpoint or error fires, [`RingBufferSink::records`] gives the preceding
+/// instruction history for post-mortem analysis.
+pub struct RingBufferSink {
+    capacity: usize,
+    buffer: VecDeque<TraceRecord>,
+}
+
+impl RingBufferSink {
+    /// Create a ring buffer retaining at most `capacity` records (a capacity of 0
+    /// is treated as 1).
+    pub fn new(capacity: usize) -> Self {
+        RingBufferSink {
+            capacity: capacity.max(1),
+            buffer: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// The records currently retained, oldest first.
+    pub fn records(&self) -> impl Iterator<Item = &TraceRecord> {
+        self.buffer.iter()
+    }
+
+    /// Number of records currently retained.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Whether the ring buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
+
+impl TraceSink for RingBufferSink {
+    fn record(&mut self, record: TraceRecord) {
+        if self.buffer.len() == self.capacity {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(record);
+    }
+}
+
+impl<T: TraceSink> TraceSink for Rc<RefCell<T>> {
+    fn record(&mut self, record: TraceRecord) {
+        self.borrow_mut().record(record);
+    }
+}
+
+/// Ties a [`TraceFilter`] to a [`TraceSink`], the unit installed on an [`Interpreter`]
+/// or [`Debugger`].
+///
+/// [`Interpreter`]: crate::interpreter::Interpreter
+/// [`Debugger`]: crate::debugger::Debugger
+pub struct Tracer {
+    filter: TraceFilter,
+    sink: Box<dyn TraceSink>,
+}
+
+impl Tracer {
+    /// Create a tracer that sends records matching `filter` to `sink`.
+    pub fn new(filter: TraceFilter, sink: Box<dyn TraceSink>) -> Self {
+        Tracer { filter, sink }
+    }
+
+    /// Offer a record to this tracer; it is forwarded to the sink only if accepted.
+    pub fn offer(&mut self, record: TraceRecord) {
+        if self.filter.matches(&record) {
+            self.sink.record(record);
+        }
+    }
+}
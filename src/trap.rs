@@ -0,0 +1,137 @@
+//! Structured fault classification for `Interpreter`/`VM`.
+//!
+//! `Instruction::decode` and the VM's execution paths currently surface every
+//! fault as an ad-hoc `String`, which pushes embedding tools (disassembler
+//! validators, debuggers, fuzzers) toward brittle substring matching like
+//! `e.contains("Invalid Long form opcode 0x00")`. [`Trap::classify`] turns one
+//! of those messages into a structured [`Trap`] so callers can match on a
+//! variant instead, and [`TrapAction`] lets an installed handler decide
+//! whether a fault should abort, be ignored, or resume with a substitute
+//! value.
+
+/// A classified fault raised while decoding or executing an instruction.
+///
+/// [`Trap::classify`] is the bridge from today's `String` error paths to this
+/// enum; as call sites are migrated to return traps directly, `Other` should
+/// shrink to nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trap {
+    /// Decoding hit an opcode that isn't valid for its form (e.g. Long form
+    /// opcode `0x00`, which never appears in real code and indicates the
+    /// decoder walked into data).
+    BadOpcode { form: String, opcode: u8 },
+    /// A read or write targeted an address outside the game's memory.
+    AddressOutOfBounds { addr: u32 },
+    /// A routine header declared more locals than the Z-Machine allows (> 15).
+    InvalidLocalsCount(u8),
+    /// The evaluation stack was popped while empty.
+    StackUnderflow,
+    /// A `div` or `mod` instruction was asked to divide by zero.
+    DivisionByZero,
+    /// An object-table operation referenced an object number outside the
+    /// valid range (typically object 0 or past the table's end).
+    InvalidObject(u16),
+    /// A packed address unpacked to a byte address past the end of memory.
+    PackedAddressOverflow { addr: u32 },
+    /// A write targeted static/high memory or a caller-marked protected
+    /// range (see `VM::protect_range`).
+    WriteToReadOnlyMemory { addr: u32 },
+    /// A fault that doesn't (yet) have a structured variant; carries the
+    /// original message so nothing is lost.
+    Other(String),
+}
+
+impl Trap {
+    /// Classify a legacy `String` error message into a structured [`Trap`].
+    ///
+    /// This is deliberately conservative: it only recognizes the exact
+    /// messages produced today by `Instruction::decode` and the VM, falling
+    /// back to [`Trap::Other`] for anything it doesn't recognize rather than
+    /// guessing.
+    pub fn classify(message: &str) -> Trap {
+        if let Some(rest) = message.strip_prefix("Invalid Long form opcode 0x00 at address ") {
+            let addr = u32::from_str_radix(rest.trim_end_matches(':'), 16).unwrap_or(0);
+            let _ = addr; // address is informational only; form/opcode identify the fault
+            return Trap::BadOpcode {
+                form: "Long".to_string(),
+                opcode: 0x00,
+            };
+        }
+
+        if let Some(rest) = message.strip_prefix("Attempt to write to read-only memory at ") {
+            if let Ok(addr) = u32::from_str_radix(rest.trim(), 16) {
+                return Trap::WriteToReadOnlyMemory { addr };
+            }
+        }
+
+        if let Some(rest) = message.strip_prefix("Instruction address ") {
+            if let Some(addr_str) = rest.strip_suffix(" out of bounds") {
+                if let Ok(addr) = addr_str.parse::<u32>() {
+                    return Trap::AddressOutOfBounds { addr };
+                }
+            }
+        }
+
+        if message.contains("out of bounds") {
+            return Trap::AddressOutOfBounds { addr: 0 };
+        }
+
+        if message == "Stack underflow" {
+            return Trap::StackUnderflow;
+        }
+
+        if message == "Division by zero" {
+            return Trap::DivisionByZero;
+        }
+
+        if let Some(rest) = message.strip_prefix("Invalid object number: ") {
+            if let Ok(obj_num) = rest.trim().parse::<u16>() {
+                return Trap::InvalidObject(obj_num);
+            }
+        }
+
+        if message.starts_with("Invalid locals count: ") {
+            if let Some(rest) = message.strip_prefix("Invalid locals count: ") {
+                if let Ok(count) = rest.trim().parse::<u32>() {
+                    return Trap::InvalidLocalsCount(count.min(u8::MAX as u32) as u8);
+                }
+            }
+        }
+
+        Trap::Other(message.to_string())
+    }
+}
+
+impl std::fmt::Display for Trap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trap::BadOpcode { form, opcode } => {
+                write!(f, "bad {form} form opcode 0x{opcode:02x}")
+            }
+            Trap::AddressOutOfBounds { addr } => write!(f, "address 0x{addr:05x} out of bounds"),
+            Trap::InvalidLocalsCount(count) => write!(f, "invalid locals count: {count}"),
+            Trap::StackUnderflow => write!(f, "stack underflow"),
+            Trap::DivisionByZero => write!(f, "division by zero"),
+            Trap::InvalidObject(obj_num) => write!(f, "invalid object number: {obj_num}"),
+            Trap::PackedAddressOverflow { addr } => {
+                write!(f, "packed address unpacks past end of memory: 0x{addr:05x}")
+            }
+            Trap::WriteToReadOnlyMemory { addr } => {
+                write!(f, "write to read-only memory at 0x{addr:04x}")
+            }
+            Trap::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// What an installed trap handler wants to happen next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapAction {
+    /// Propagate the fault as an error, same as if no handler were installed.
+    Abort,
+    /// Swallow the fault and keep running.
+    Continue,
+    /// Swallow the fault and, if the faulting instruction stored a result,
+    /// store this value instead.
+    ResumeWith(u16),
+}
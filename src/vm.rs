@@ -8,6 +8,39 @@ const STACK_SIZE: usize = 1024;
 /// Maximum number of local variables per routine
 const MAX_LOCALS: usize = 16;
 
+/// Tracks which memory ranges are protected from writes.
+///
+/// Models the Z-Machine's dynamic/static/high memory boundary (everything
+/// at or past the header's static-memory base is read-only) plus any
+/// additional ranges a caller has fenced off via [`VM::protect_range`].
+struct MemoryProtection {
+    /// First address no longer writable (static memory base, from the header).
+    static_base: u32,
+    /// Additional `[start, end)` ranges marked read-only on top of static memory.
+    extra_ranges: Vec<(u32, u32)>,
+    /// When true, a write into a protected range raises a fault instead of
+    /// being logged and ignored.
+    strict: bool,
+}
+
+impl MemoryProtection {
+    fn new(static_base: u32) -> Self {
+        MemoryProtection {
+            static_base,
+            extra_ranges: Vec::new(),
+            strict: false,
+        }
+    }
+
+    fn is_protected(&self, addr: u32) -> bool {
+        addr >= self.static_base
+            || self
+                .extra_ranges
+                .iter()
+                .any(|&(start, end)| addr >= start && addr < end)
+    }
+}
+
 /// Represents a call frame on the VM call stack
 #[derive(Debug, Clone)]
 pub struct CallFrame {
@@ -66,6 +99,8 @@ pub struct VM {
     globals_addr: u16,
     /// Current instruction PC (for debugging - set by interpreter before execution)
     pub current_instruction_pc: Option<u32>,
+    /// Write protection for static/high memory and caller-marked ranges
+    memory_protection: MemoryProtection,
 }
 
 impl VM {
@@ -73,6 +108,7 @@ impl VM {
     pub fn new(game: Game) -> Self {
         let initial_pc = game.header.initial_pc as u32;
         let globals_addr = game.header.global_variables as u16;
+        let static_base = game.header.base_static_mem as u32;
 
         // For V1-5, we need to set up an initial "main" context
         // that has no locals but allows stack operations
@@ -83,6 +119,7 @@ impl VM {
             call_stack: Vec::new(),
             globals_addr,
             current_instruction_pc: None,
+            memory_protection: MemoryProtection::new(static_base),
         };
 
         // Set up initial call frame for V1-5 (V6+ uses main routine)
@@ -282,13 +319,22 @@ impl VM {
         (high << 8) | low
     }
 
-    /// Write a byte to memory (only in dynamic memory)
+    /// Write a byte to memory (only in dynamic, unprotected memory)
+    ///
+    /// Writes into static/high memory or a caller-marked protected range are
+    /// a [`crate::trap::Trap::WriteToReadOnlyMemory`] fault: in strict mode
+    /// (see [`VM::set_strict_memory_protection`]) this is returned as an
+    /// error, classifiable by callers via [`crate::trap::Trap::classify`];
+    /// by default (lenient mode) the write is logged and silently dropped.
     pub fn write_byte(&mut self, addr: u32, value: u8) -> Result<(), String> {
-        let dynamic_limit = self.game.header.base_static_mem as u32;
-        if addr >= dynamic_limit {
-            return Err(format!(
-                "Attempt to write to non-dynamic memory at {addr:04x}"
-            ));
+        if self.memory_protection.is_protected(addr) {
+            if self.memory_protection.strict {
+                return Err(format!(
+                    "Attempt to write to read-only memory at {addr:04x}"
+                ));
+            }
+            debug!("Ignoring write of 0x{value:02x} to read-only memory at {addr:04x}");
+            return Ok(());
         }
         if addr < self.game.memory.len() as u32 {
             self.game.memory[addr as usize] = value;
@@ -298,6 +344,21 @@ impl VM {
         }
     }
 
+    /// Enable or disable strict memory protection.
+    ///
+    /// In strict mode, a write to static/high memory or a caller-marked
+    /// protected range raises a [`crate::trap::Trap::WriteToReadOnlyMemory`]
+    /// fault instead of being logged and ignored.
+    pub fn set_strict_memory_protection(&mut self, strict: bool) {
+        self.memory_protection.strict = strict;
+    }
+
+    /// Mark an additional `[start, end)` range read-only, on top of the
+    /// static/high memory boundary derived from the header.
+    pub fn protect_range(&mut self, start: u32, end: u32) {
+        self.memory_protection.extra_ranges.push((start, end));
+    }
+
     /// Write a word to memory (only in dynamic memory)
     pub fn write_word(&mut self, addr: u32, value: u16) -> Result<(), String> {
         // Track writes to score/moves globals at 0x42 (score) and 0x44 (moves)
@@ -1462,8 +1523,35 @@ mod tests {
         vm.write_word(0x100, 0xCDEF).unwrap();
         assert_eq!(vm.read_word(0x100), 0xCDEF);
 
-        // Test write to static memory (should fail)
+        // Test write to static memory: lenient by default (logged and ignored)
+        vm.write_byte(0x300, 0xFF).unwrap();
+        assert_eq!(vm.read_byte(0x300), 0x00);
+
+        // Strict mode turns the same write into a fault
+        vm.set_strict_memory_protection(true);
         assert!(vm.write_byte(0x300, 0xFF).is_err());
+        assert_eq!(vm.read_byte(0x300), 0x00);
+    }
+
+    #[test]
+    fn test_protect_range() {
+        let mut vm = create_test_vm();
+
+        // 0x100 is ordinarily writable dynamic memory
+        vm.write_byte(0x100, 0xAB).unwrap();
+        assert_eq!(vm.read_byte(0x100), 0xAB);
+
+        // Fence it off explicitly
+        vm.protect_range(0x100, 0x110);
+        vm.write_byte(0x100, 0xCD).unwrap();
+        assert_eq!(vm.read_byte(0x100), 0xAB); // write was ignored
+
+        vm.set_strict_memory_protection(true);
+        assert!(vm.write_byte(0x100, 0xCD).is_err());
+
+        // Addresses outside the marked range are unaffected
+        vm.write_byte(0x110, 0xEF).unwrap();
+        assert_eq!(vm.read_byte(0x110), 0xEF);
     }
 
     #[test]
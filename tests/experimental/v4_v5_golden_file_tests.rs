@@ -73,7 +73,7 @@ fn compile_grue_file(
         .tokenize()
         .map_err(|e| format!("Lexer error: {:?}", e))?;
 
-    let mut parser = parser::Parser::new(tokens);
+    let mut parser = parser::Parser::new(tokens, &source_content);
     let ast = parser
         .parse()
         .map_err(|e| format!("Parser error: {:?}", e))?;
@@ -221,7 +221,7 @@ fn compile_grue_file(source_path: &Path, version: ZMachineVersion) -> Result<Vec
         .map_err(|e| format!("Tokenization failed: {}", e))?;
 
     // Phase 2: Parsing
-    let mut parser = parser::Parser::new(tokens);
+    let mut parser = parser::Parser::new(tokens, &source_content);
     let ast = parser.parse()
         .map_err(|e| format!("Parsing failed: {}", e))?;
 
@@ -342,7 +342,7 @@ fn compile_grue_file(
         .tokenize()
         .map_err(|e| format!("Lexer error: {:?}", e))?;
 
-    let mut parser = parser::Parser::new(tokens);
+    let mut parser = parser::Parser::new(tokens, &source_content);
     let ast = parser
         .parse()
         .map_err(|e| format!("Parser error: {:?}", e))?;